@@ -1,4 +1,5 @@
 use crate::Locale;
+use alloc::string::String;
 
 #[cfg(feature = "crypto")]
 mod crypto_currencies;
@@ -10,8 +11,19 @@ mod iso_currencies;
 #[cfg(feature = "iso")]
 pub use iso_currencies::iso;
 
+#[cfg(all(feature = "std", any(feature = "iso", feature = "crypto")))]
+mod any_currency;
+#[cfg(all(feature = "std", any(feature = "iso", feature = "crypto")))]
+pub use any_currency::AnyCurrency;
+
 /// Pre-requisite for a Currency to be accepted by a Money.
+///
+/// Currencies are looked up through `find`, so generic code can resolve a code into a
+/// currency of any set (e.g. `T::find("USD")`) with a single bound.
 pub trait FormattableCurrency: PartialEq + Eq + Copy {
+    /// Returns the currency in this set with the given code, if it exists.
+    fn find(code: &str) -> Option<&'static Self>;
+
     fn to_string(&self) -> String;
 
     fn exponent(&self) -> u32;
@@ -20,13 +32,48 @@ pub trait FormattableCurrency: PartialEq + Eq + Copy {
 
     fn locale(&self) -> Locale;
 
+    /// Returns the smallest cash denomination in minor units (e.g. 5 for CHF, which has no coins
+    /// smaller than 5 centimes).
+    fn minor_units(&self) -> u64;
+
+    /// Returns the full name of the currency (e.g. United States Dollar).
+    fn name(&self) -> &'static str;
+
     fn symbol(&self) -> &'static str;
 
+    /// Returns the symbol used for cash amounts, if it differs from the accounting symbol.
+    fn cash_symbol(&self) -> &'static str {
+        self.symbol()
+    }
+
     fn symbol_first(&self) -> bool;
+
+    /// Returns other symbols commonly used for the currency (e.g. US$ for USD).
+    fn alternate_symbols(&self) -> &'static [&'static str] {
+        &[]
+    }
+}
+
+#[doc(hidden)]
+#[macro_export]
+/// Expands to the value of an optional currency field, or the default if it was omitted.
+macro_rules! __currency_field_or {
+    ($value:expr, $default:expr) => {
+        $value
+    };
+    (, $default:expr) => {
+        $default
+    };
 }
 
 #[macro_export]
 /// Create custom currencies for use with Money types
+///
+/// Fields are listed in alphabetical order, starting with the optional ones. A currency may list
+/// other common symbols as `alternate_symbols: ["US$"]`, which default to none. It may then set a
+/// `cash_symbol`, used by `Money::format_cash`, which defaults to its `symbol`. Last, it may name
+/// a `marker` type, which is generated in the module and implements `CurrencyMarker` for use with
+/// `TypedMoney`.
 macro_rules! define_currency_set {
     (
         $(
@@ -34,6 +81,9 @@ macro_rules! define_currency_set {
             $module:ident {
                 $(
                     $currency:ident: {
+                    $(alternate_symbols: [$($alt_sym:expr),* $(,)?],)?
+                    $(cash_symbol: $cash_sym:expr,)?
+                    $(marker: $marker:ident,)?
                     code: $code:expr,
                     exponent: $exp:expr,
                     locale: $loc:expr,
@@ -50,10 +100,13 @@ macro_rules! define_currency_set {
                 $(#[$attr])*
                 pub mod $module {
                     use $crate::{Locale, FormattableCurrency, Locale::*};
-                    use std::fmt;
+                    use $crate::__private::{String, ToString};
+                    use core::fmt;
 
                     #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
                     pub struct Currency {
+                        pub alternate_symbols: &'static [&'static str],
+                        pub cash_symbol: &'static str,
                         pub code: &'static str,
                         pub exponent: u32,
                         pub locale: Locale,
@@ -64,6 +117,10 @@ macro_rules! define_currency_set {
                     }
 
                     impl FormattableCurrency for Currency {
+                        fn find(code: &str) -> Option<&'static Currency> {
+                            find(code)
+                        }
+
                         fn to_string(&self) -> String {
                             self.code().to_string()
                         }
@@ -80,17 +137,35 @@ macro_rules! define_currency_set {
                             self.locale
                         }
 
+                        fn minor_units(&self) -> u64 {
+                            self.minor_units
+                        }
+
+                        fn name(&self) -> &'static str {
+                            self.name
+                        }
+
                         fn symbol(&self) -> &'static str {
                             self.symbol
                         }
 
+                        fn cash_symbol(&self) -> &'static str {
+                            self.cash_symbol
+                        }
+
                         fn symbol_first(&self) -> bool {
                             self.symbol_first
                         }
+
+                        fn alternate_symbols(&self) -> &'static [&'static str] {
+                            self.alternate_symbols
+                        }
                     }
 
                     $(
                         pub const $currency: &'static self::Currency = &self::Currency {
+                        alternate_symbols: $crate::__currency_field_or!($(&[$($alt_sym),*])?, &[]),
+                        cash_symbol: $crate::__currency_field_or!($($cash_sym)?, $sym),
                         code: $code,
                         exponent: $exp,
                         locale: $loc,
@@ -99,6 +174,20 @@ macro_rules! define_currency_set {
                         symbol: $sym,
                         symbol_first: $sym_first,
                         };
+
+                        $(
+                            /// Marks TypedMoney in this currency.
+                            #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+                            pub struct $marker;
+
+                            impl $crate::CurrencyMarker for $marker {
+                                type Currency = self::Currency;
+
+                                fn currency() -> &'static self::Currency {
+                                    $currency
+                                }
+                            }
+                        )?
                     )+
 
                     pub fn find(code: &str) -> Option<&'static self::Currency> {
@@ -108,11 +197,45 @@ macro_rules! define_currency_set {
                         }
                     }
 
+                    /// Returns every currency defined in this set.
+                    #[allow(dead_code)]
+                    pub fn all() -> &'static [&'static self::Currency] {
+                        &[$($currency),+]
+                    }
+
+                    /// Money denominated in a currency from this set.
+                    #[allow(dead_code)]
+                    pub type Money = $crate::Money<'static, self::Currency>;
+
+                    /// Creates a Money from minor units and the code of a currency in this set.
+                    #[allow(dead_code)]
+                    pub fn money(minor: i64, code: &str) -> Result<Money, $crate::MoneyError> {
+                        let currency = find(code).ok_or($crate::MoneyError::InvalidCurrency)?;
+                        Ok($crate::Money::from_minor(minor, currency))
+                    }
+
                     impl fmt::Display for Currency {
                         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
                             write!(f, "{}", self.code)
                         }
                     }
+
+                    /// Copies the fields of a currency from any other set (e.g. `iso::USD`) into this one.
+                    impl<C: FormattableCurrency> From<&C> for Currency {
+                        fn from(currency: &C) -> Currency {
+                            Currency {
+                                alternate_symbols: currency.alternate_symbols(),
+                                cash_symbol: currency.cash_symbol(),
+                                code: currency.code(),
+                                exponent: currency.exponent(),
+                                locale: currency.locale(),
+                                minor_units: currency.minor_units(),
+                                name: currency.name(),
+                                symbol: currency.symbol(),
+                                symbol_first: currency.symbol_first(),
+                            }
+                        }
+                    }
                 }
             )+
     };
@@ -120,9 +243,12 @@ macro_rules! define_currency_set {
 
 #[cfg(test)]
 mod tests {
+    use super::FormattableCurrency;
+
     define_currency_set!(
       real {
         USD: {
+          alternate_symbols: ["US$", "USD$"],
           code: "USD",
           exponent: 2,
           locale: EnUs,
@@ -142,18 +268,147 @@ mod tests {
             symbol: "F",
             symbol_first: true,
           }
+      },
+      cash {
+        CHF: {
+          alternate_symbols: ["SFr."],
+          cash_symbol: "Fr.",
+          marker: Chf,
+          code: "CHF",
+          exponent: 2,
+          locale: EnEu,
+          minor_units: 5,
+          name: "Swiss Franc",
+          symbol: "CHF",
+          symbol_first: true,
+        }
       }
     );
 
+    #[test]
+    fn optional_fields_are_listed_alphabetically() {
+        assert_eq!(cash::CHF.alternate_symbols(), &["SFr."]);
+        assert_eq!(cash::CHF.cash_symbol(), "Fr.");
+        assert_eq!(<cash::Chf as crate::CurrencyMarker>::currency(), cash::CHF);
+    }
+
     #[test]
     fn currencies_in_different_modules_are_not_equal() {
         assert_eq!(real::USD.code, "USD");
         assert_eq!(magic::FOO.code, "FOO");
     }
 
+    #[test]
+    fn all_lists_every_currency_in_module() {
+        assert_eq!(real::all(), &[real::USD]);
+        assert_eq!(magic::all(), &[magic::FOO]);
+    }
+
     #[test]
     fn find_works_in_modules() {
         assert_eq!(real::find("USD").unwrap().code, "USD");
         assert_eq!(magic::find("FOO").unwrap().code, "FOO");
     }
+
+    #[test]
+    fn money_alias_and_helper_work_in_modules() {
+        let money: real::Money = real::money(100_050, "USD").unwrap();
+        assert_eq!(money, crate::Money::from_minor(100_050, real::USD));
+        assert_eq!(money.to_string(), "$1,000.50");
+
+        let money: magic::Money = magic::money(1_500, "FOO").unwrap();
+        assert_eq!(money.to_string(), "F1.500");
+
+        assert_eq!(
+            magic::money(100, "USD").unwrap_err(),
+            crate::MoneyError::InvalidCurrency
+        );
+    }
+
+    #[test]
+    fn alternate_symbols_default_to_empty() {
+        assert_eq!(real::USD.alternate_symbols(), &["US$", "USD$"]);
+        assert!(magic::FOO.alternate_symbols().is_empty());
+
+        let usd = magic::Currency::from(real::USD);
+        assert_eq!(usd.alternate_symbols, &["US$", "USD$"]);
+    }
+
+    #[test]
+    fn currencies_convert_between_modules() {
+        let foo = real::Currency::from(magic::FOO);
+        assert_eq!(foo.code, "FOO");
+        assert_eq!(foo.exponent, 3);
+        assert_eq!(foo.symbol, "F");
+        assert!(real::find("FOO").is_none());
+
+        let usd: magic::Currency = real::USD.into();
+        assert_eq!(magic::Currency::from(&usd), usd);
+    }
+
+    #[test]
+    #[cfg(feature = "iso")]
+    fn iso_currencies_convert_into_modules() {
+        let usd = real::Currency::from(crate::iso::USD);
+        assert_eq!(usd.code, "USD");
+        assert_eq!(usd.exponent, crate::iso::USD.exponent);
+        assert_eq!(usd.locale, crate::iso::USD.locale);
+        assert_eq!(usd.minor_units, crate::iso::USD.minor_units);
+        assert_eq!(usd.name, "United States Dollar");
+        assert_eq!(usd.symbol, "$");
+        assert!(usd.symbol_first);
+
+        let money = crate::Money::from_minor(100_050, &usd);
+        assert_eq!(money.to_string(), "$1,000.50");
+    }
+
+    fn load<T: FormattableCurrency + 'static>(code: &str) -> Option<&'static T> {
+        T::find(code)
+    }
+
+    fn selector_labels<T: FormattableCurrency>(currencies: &[&T]) -> Vec<String> {
+        currencies
+            .iter()
+            .map(|currency| format!("{} ({})", currency.name(), currency.code()))
+            .collect()
+    }
+
+    #[test]
+    fn name_is_available_through_the_trait() {
+        assert_eq!(selector_labels(real::all()), vec!["USD (USD)"]);
+        assert_eq!(selector_labels(magic::all()), vec!["FOO (FOO)"]);
+
+        #[cfg(feature = "iso")]
+        assert_eq!(
+            selector_labels(&[crate::iso::USD, crate::iso::EUR]),
+            vec!["United States Dollar (USD)", "Euro (EUR)"]
+        );
+        #[cfg(feature = "crypto")]
+        assert_eq!(
+            selector_labels(&[crate::crypto::BTC]),
+            vec!["Bitcoin (BTC)"]
+        );
+    }
+
+    fn smallest_coin<T: FormattableCurrency>(currency: &T) -> crate::Money<'_, T> {
+        crate::Money::from_minor(currency.minor_units() as i64, currency)
+    }
+
+    #[test]
+    fn minor_units_is_available_through_the_trait() {
+        assert_eq!(smallest_coin(cash::CHF).to_string(), "CHF0,05");
+
+        #[cfg(feature = "iso")]
+        {
+            assert_eq!(smallest_coin(crate::iso::USD).to_string(), "$0.01");
+            assert_eq!(smallest_coin(crate::iso::CHF).to_string(), "Fr0.05");
+        }
+    }
+
+    #[test]
+    fn find_works_through_the_trait() {
+        assert_eq!(load::<real::Currency>("USD"), Some(real::USD));
+        assert_eq!(load::<magic::Currency>("FOO"), Some(magic::FOO));
+        assert_eq!(load::<magic::Currency>("USD"), None);
+    }
 }
@@ -0,0 +1,192 @@
+use crate::{FormattableCurrency, Locale};
+use std::fmt;
+use std::sync::OnceLock;
+
+#[cfg(feature = "crypto")]
+use crate::crypto;
+#[cfg(feature = "iso")]
+use crate::iso;
+
+/// A currency from any of the built-in sets, so Money in different sets can be stored together.
+///
+/// Currencies are equal only if they are from the same set and have the same code, so
+/// `AnyCurrency::Iso(iso::USD)` and a crypto currency coded USD are different.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum AnyCurrency {
+    #[cfg(feature = "iso")]
+    Iso(&'static iso::Currency),
+    #[cfg(feature = "crypto")]
+    Crypto(&'static crypto::Currency),
+}
+
+impl AnyCurrency {
+    /// Returns every currency of the enabled sets, ISO currencies first.
+    pub fn all() -> &'static [AnyCurrency] {
+        static ALL: OnceLock<Vec<AnyCurrency>> = OnceLock::new();
+        ALL.get_or_init(|| {
+            let mut currencies = Vec::new();
+            #[cfg(feature = "iso")]
+            currencies.extend(
+                iso::all()
+                    .iter()
+                    .map(|&currency| AnyCurrency::Iso(currency)),
+            );
+            #[cfg(feature = "crypto")]
+            currencies.extend(
+                crypto::all()
+                    .iter()
+                    .map(|&currency| AnyCurrency::Crypto(currency)),
+            );
+            currencies
+        })
+    }
+}
+
+/// Calls a FormattableCurrency method on the currency wrapped by an AnyCurrency.
+macro_rules! delegate {
+    ($any:ident.$method:ident()) => {
+        match *$any {
+            #[cfg(feature = "iso")]
+            AnyCurrency::Iso(currency) => currency.$method(),
+            #[cfg(feature = "crypto")]
+            AnyCurrency::Crypto(currency) => currency.$method(),
+        }
+    };
+}
+
+impl FormattableCurrency for AnyCurrency {
+    /// Returns the currency with the given code, preferring ISO currencies over crypto currencies.
+    fn find(code: &str) -> Option<&'static AnyCurrency> {
+        AnyCurrency::all()
+            .iter()
+            .find(|currency| currency.code() == code)
+    }
+
+    fn to_string(&self) -> String {
+        self.code().to_string()
+    }
+
+    fn exponent(&self) -> u32 {
+        delegate!(self.exponent())
+    }
+
+    fn code(&self) -> &'static str {
+        delegate!(self.code())
+    }
+
+    fn locale(&self) -> Locale {
+        delegate!(self.locale())
+    }
+
+    fn minor_units(&self) -> u64 {
+        delegate!(self.minor_units())
+    }
+
+    fn name(&self) -> &'static str {
+        delegate!(self.name())
+    }
+
+    fn symbol(&self) -> &'static str {
+        delegate!(self.symbol())
+    }
+
+    fn cash_symbol(&self) -> &'static str {
+        delegate!(self.cash_symbol())
+    }
+
+    fn symbol_first(&self) -> bool {
+        delegate!(self.symbol_first())
+    }
+
+    fn alternate_symbols(&self) -> &'static [&'static str] {
+        delegate!(self.alternate_symbols())
+    }
+}
+
+impl fmt::Display for AnyCurrency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+#[cfg(feature = "iso")]
+impl From<&'static iso::Currency> for AnyCurrency {
+    fn from(currency: &'static iso::Currency) -> AnyCurrency {
+        AnyCurrency::Iso(currency)
+    }
+}
+
+#[cfg(feature = "crypto")]
+impl From<&'static crypto::Currency> for AnyCurrency {
+    fn from(currency: &'static crypto::Currency) -> AnyCurrency {
+        AnyCurrency::Crypto(currency)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Money;
+
+    #[test]
+    #[cfg(feature = "iso")]
+    fn any_currency_delegates_to_iso() {
+        let usd = AnyCurrency::from(iso::USD);
+        assert_eq!(usd.code(), "USD");
+        assert_eq!(usd.exponent(), 2);
+        assert_eq!(usd.name(), "United States Dollar");
+        assert_eq!(AnyCurrency::find("USD"), Some(&usd));
+        assert_eq!(AnyCurrency::find("FAKE"), None);
+
+        let money = Money::from_minor(100_050, &usd);
+        assert_eq!(money.to_string(), "$1,000.50");
+    }
+
+    #[test]
+    #[cfg(feature = "crypto")]
+    fn any_currency_delegates_to_crypto() {
+        let btc = AnyCurrency::from(crypto::BTC);
+        assert_eq!(btc.code(), "BTC");
+        assert_eq!(btc.exponent(), 8);
+        assert_eq!(btc.symbol(), "₿");
+        assert_eq!(AnyCurrency::find("BTC"), Some(&btc));
+
+        let money = Money::from_minor(50_000_000, &btc);
+        assert_eq!(money.to_string(), "₿0.50000000");
+    }
+
+    #[test]
+    #[cfg(all(feature = "iso", feature = "crypto"))]
+    fn any_currency_holds_mixed_currencies() {
+        let usd = AnyCurrency::from(iso::USD);
+        let btc = AnyCurrency::from(crypto::BTC);
+
+        let mut balances: std::collections::HashMap<String, Money<AnyCurrency>> =
+            std::collections::HashMap::new();
+        balances.insert("cash".to_string(), Money::from_minor(1_000, &usd));
+        balances.insert("wallet".to_string(), Money::from_minor(50_000_000, &btc));
+
+        assert_eq!(balances["cash"].to_string(), "$10.00");
+        assert_eq!(balances["wallet"].to_string(), "₿0.50000000");
+        assert_eq!(AnyCurrency::find("BTC"), Some(&btc));
+        assert_eq!(
+            AnyCurrency::all().len(),
+            iso::all().len() + crypto::all().len()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "iso")]
+    fn any_currency_equality_compares_set_and_code() {
+        assert_eq!(AnyCurrency::from(iso::USD), AnyCurrency::from(iso::USD));
+        assert_ne!(AnyCurrency::from(iso::USD), AnyCurrency::from(iso::EUR));
+
+        // The same currency data in another set is a different currency
+        #[cfg(feature = "crypto")]
+        {
+            let usd: &'static crypto::Currency = Box::leak(Box::new(iso::USD.into()));
+            assert_eq!(usd.code(), "USD");
+            assert_ne!(AnyCurrency::Iso(iso::USD), AnyCurrency::Crypto(usd));
+        }
+    }
+}
@@ -140,8 +140,26 @@ mod tests {
         assert_eq!(crypto::ETH.symbol, "ETH");
     }
 
+    #[test]
+    fn all_lists_every_currency() {
+        let currencies = crypto::all();
+        assert!(currencies.contains(&crypto::BTC));
+        assert!(currencies
+            .iter()
+            .all(|currency| crypto::find(currency.code) == Some(*currency)));
+    }
+
     #[test]
     fn find_and_reference_point_to_same() {
         assert_eq!(crypto::BTC, crypto::find("BTC").unwrap());
     }
+
+    #[test]
+    fn money_from_str_with_code_finds_crypto_currencies() {
+        let money = crate::Money::<crypto::Currency>::from_str_with_code("0.5", "BTC").unwrap();
+        assert_eq!(money, crate::Money::from_minor(50_000_000, crypto::BTC));
+
+        let err = crate::Money::<crypto::Currency>::from_str_with_code("0.5", "USD").unwrap_err();
+        assert_eq!(err, crate::MoneyError::InvalidCurrency);
+    }
 }
@@ -1,7 +1,9 @@
 /// ISO-4217 Currency Set
 pub mod iso {
     use crate::{FormattableCurrency, Locale, Locale::*};
-    use std::fmt;
+    use alloc::string::{String, ToString};
+    use alloc::vec::Vec;
+    use core::fmt;
 
     /// Represents a single ISO-4217 currency (e.g. USD).
     #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
@@ -14,9 +16,15 @@ pub mod iso {
         pub name: &'static str,
         pub symbol: &'static str,
         pub symbol_first: bool,
+        /// The year and month the currency was withdrawn (e.g. "2002-03"), or None if it is active.
+        pub withdrawal_date: Option<&'static str>,
     }
 
     impl FormattableCurrency for Currency {
+        fn find(code: &str) -> Option<&'static Currency> {
+            find(code)
+        }
+
         fn to_string(&self) -> String {
             self.code().to_string()
         }
@@ -33,6 +41,14 @@ pub mod iso {
             self.locale
         }
 
+        fn minor_units(&self) -> u64 {
+            self.minor_units
+        }
+
+        fn name(&self) -> &'static str {
+            self.name
+        }
+
         fn symbol(&self) -> &'static str {
             self.symbol
         }
@@ -42,6 +58,21 @@ pub mod iso {
         }
     }
 
+    impl Currency {
+        /// Returns a copy of this currency with a different exponent.
+        ///
+        /// Allows exponents loaded from another data source to override the ISO-4217 table
+        /// (e.g. HUF, whose official exponent of 2 is rarely used in practice).
+        pub const fn with_exponent(&self, exponent: u32) -> Currency {
+            Currency { exponent, ..*self }
+        }
+
+        /// Returns true if the currency has not been withdrawn.
+        pub const fn is_active(&self) -> bool {
+            self.withdrawal_date.is_none()
+        }
+    }
+
     impl fmt::Display for Currency {
         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             write!(f, "{}", self.iso_alpha_code)
@@ -60,6 +91,7 @@ pub mod iso {
                 name: $name:expr,
                 symbol: $sym:expr,
                 symbol_first: $sym_first:expr,
+                $(withdrawal_date: $date:expr,)?
             }
       ),+
     ) => {
@@ -73,9 +105,11 @@ pub mod iso {
             name: $name,
             symbol: $sym,
             symbol_first: $sym_first,
+            withdrawal_date: $crate::__currency_field_or!($(Some($date))?, None),
         };
       )+
 
+      /// Returns the currency with the given code, including withdrawn currencies such as DEM.
       pub fn find(code: &str) -> Option<&'static Currency> {
         match code {
           $($alpha_code => (Some($currency)),)+
@@ -83,11 +117,21 @@ pub mod iso {
         }
       }
 
+      /// Returns the currency with the given numeric code, preferring an active currency over a
+      /// withdrawn one that used the same code (e.g. "532" is XCG, which replaced ANG).
       pub fn find_by_num_code(code: &str) -> Option<&'static Currency> {
-        match code {
-          $($num_code => (Some($currency)),)+
-          _ => None,
-        }
+        let matching = || {
+          all()
+            .iter()
+            .copied()
+            .filter(move |currency| currency.iso_numeric_code == code)
+        };
+        matching().find(|currency| currency.is_active()).or_else(|| matching().next())
+      }
+
+      /// Returns every ISO-4217 currency.
+      pub fn all() -> &'static [&'static Currency] {
+        &[$($currency),+]
       }
     };
   }
@@ -142,6 +186,7 @@ pub mod iso {
             name: "Netherlands Antillean Gulden",
             symbol: "ƒ",
             symbol_first: false,
+            withdrawal_date: "2025-06",
         },
         AOA : {
             exponent: 2,
@@ -163,6 +208,17 @@ pub mod iso {
             symbol: "$",
             symbol_first: true,
         },
+        ATS : {
+            exponent: 2,
+            iso_alpha_code: "ATS",
+            iso_numeric_code: "040",
+            locale: EnEu,
+            minor_units: 1,
+            name: "Austrian Schilling",
+            symbol: "öS",
+            symbol_first: true,
+            withdrawal_date: "2002-03",
+        },
         AUD : {
             exponent: 2,
             iso_alpha_code: "AUD",
@@ -224,6 +280,17 @@ pub mod iso {
             symbol_first: true,
         },
 
+        BEF : {
+            exponent: 0,
+            iso_alpha_code: "BEF",
+            iso_numeric_code: "056",
+            locale: EnEu,
+            minor_units: 1,
+            name: "Belgian Franc",
+            symbol: "fr",
+            symbol_first: false,
+            withdrawal_date: "2002-03",
+        },
         BGN : {
             exponent: 2,
             iso_alpha_code: "BGN",
@@ -285,6 +352,16 @@ pub mod iso {
             symbol: "Bs.",
             symbol_first: true,
         },
+        BOV : {
+            exponent: 2,
+            iso_alpha_code: "BOV",
+            iso_numeric_code: "984",
+            locale: EnUs,
+            minor_units: 1,
+            name: "Bolivian Mvdol",
+            symbol: "",
+            symbol_first: false,
+        },
         BRL : {
             exponent: 2,
             iso_alpha_code: "BRL",
@@ -344,6 +421,7 @@ pub mod iso {
             name: "Belarusian Ruble",
             symbol: "Br",
             symbol_first: false,
+            withdrawal_date: "2017-01",
         },
         BZD : {
             exponent: 2,
@@ -375,16 +453,36 @@ pub mod iso {
             symbol: "Fr",
             symbol_first: false,
         },
+        CHE : {
+            exponent: 2,
+            iso_alpha_code: "CHE",
+            iso_numeric_code: "947",
+            locale: DeCh,
+            minor_units: 1,
+            name: "WIR Euro",
+            symbol: "",
+            symbol_first: false,
+        },
         CHF : {
             exponent: 2,
             iso_alpha_code: "CHF",
             iso_numeric_code: "756",
-            locale: EnUs,
+            locale: DeCh,
             minor_units: 5,
             name: "Swiss Franc",
             symbol: "Fr",
             symbol_first: true,
         },
+        CHW : {
+            exponent: 2,
+            iso_alpha_code: "CHW",
+            iso_numeric_code: "948",
+            locale: DeCh,
+            minor_units: 1,
+            name: "WIR Franc",
+            symbol: "",
+            symbol_first: false,
+        },
         CLF : {
             exponent: 4,
             iso_alpha_code: "CLF",
@@ -425,6 +523,16 @@ pub mod iso {
             symbol: "$",
             symbol_first: true,
         },
+        COU : {
+            exponent: 2,
+            iso_alpha_code: "COU",
+            iso_numeric_code: "970",
+            locale: EnEu,
+            minor_units: 1,
+            name: "Unidad de Valor Real",
+            symbol: "",
+            symbol_first: false,
+        },
         CRC : {
             exponent: 2,
             iso_alpha_code: "CRC",
@@ -475,6 +583,17 @@ pub mod iso {
             symbol: "Kč",
             symbol_first: false,
         },
+        DEM : {
+            exponent: 2,
+            iso_alpha_code: "DEM",
+            iso_numeric_code: "276",
+            locale: EnEu,
+            minor_units: 1,
+            name: "Deutsche Mark",
+            symbol: "DM",
+            symbol_first: false,
+            withdrawal_date: "2002-03",
+        },
         DJF : {
             exponent: 0,
             iso_alpha_code: "DJF",
@@ -515,6 +634,17 @@ pub mod iso {
             symbol: "د.ج",
             symbol_first: false,
         },
+        EEK : {
+            exponent: 2,
+            iso_alpha_code: "EEK",
+            iso_numeric_code: "233",
+            locale: EnBy,
+            minor_units: 1,
+            name: "Estonian Kroon",
+            symbol: "kr",
+            symbol_first: false,
+            withdrawal_date: "2011-01",
+        },
         EGP : {
             exponent: 2,
             iso_alpha_code: "EGP",
@@ -535,6 +665,17 @@ pub mod iso {
             symbol: "Nfk",
             symbol_first: false,
         },
+        ESP : {
+            exponent: 0,
+            iso_alpha_code: "ESP",
+            iso_numeric_code: "724",
+            locale: EnEu,
+            minor_units: 1,
+            name: "Spanish Peseta",
+            symbol: "Pta",
+            symbol_first: false,
+            withdrawal_date: "2002-03",
+        },
         ETB : {
             exponent: 2,
             iso_alpha_code: "ETB",
@@ -555,6 +696,17 @@ pub mod iso {
             symbol: "€",
             symbol_first: true,
         },
+        FIM : {
+            exponent: 2,
+            iso_alpha_code: "FIM",
+            iso_numeric_code: "246",
+            locale: EnBy,
+            minor_units: 10,
+            name: "Finnish Markka",
+            symbol: "mk",
+            symbol_first: false,
+            withdrawal_date: "2002-03",
+        },
         FJD : {
             exponent: 2,
             iso_alpha_code: "FJD",
@@ -575,6 +727,17 @@ pub mod iso {
             symbol: "£",
             symbol_first: false,
         },
+        FRF : {
+            exponent: 2,
+            iso_alpha_code: "FRF",
+            iso_numeric_code: "250",
+            locale: EnBy,
+            minor_units: 1,
+            name: "French Franc",
+            symbol: "F",
+            symbol_first: false,
+            withdrawal_date: "2002-03",
+        },
         GBP : {
             exponent: 2,
             iso_alpha_code: "GBP",
@@ -635,6 +798,17 @@ pub mod iso {
             symbol: "Fr",
             symbol_first: false,
         },
+        GRD : {
+            exponent: 0,
+            iso_alpha_code: "GRD",
+            iso_numeric_code: "300",
+            locale: EnEu,
+            minor_units: 1,
+            name: "Greek Drachma",
+            symbol: "₯",
+            symbol_first: false,
+            withdrawal_date: "2002-03",
+        },
         GTQ : {
             exponent: 2,
             iso_alpha_code: "GTQ",
@@ -684,6 +858,7 @@ pub mod iso {
             name: "Croatian Kuna",
             symbol: "kn",
             symbol_first: false,
+            withdrawal_date: "2023-01",
         },
         HTG : {
             exponent: 2,
@@ -715,6 +890,17 @@ pub mod iso {
             symbol: "Rp",
             symbol_first: true,
         },
+        IEP : {
+            exponent: 2,
+            iso_alpha_code: "IEP",
+            iso_numeric_code: "372",
+            locale: EnUs,
+            minor_units: 1,
+            name: "Irish Pound",
+            symbol: "£",
+            symbol_first: true,
+            withdrawal_date: "2002-03",
+        },
         ILS : {
             exponent: 2,
             iso_alpha_code: "ILS",
@@ -765,6 +951,17 @@ pub mod iso {
             symbol: "kr.",
             symbol_first: true,
         },
+        ITL : {
+            exponent: 0,
+            iso_alpha_code: "ITL",
+            iso_numeric_code: "380",
+            locale: EnEu,
+            minor_units: 1,
+            name: "Italian Lira",
+            symbol: "L",
+            symbol_first: true,
+            withdrawal_date: "2002-03",
+        },
         JMD : {
             exponent: 2,
             iso_alpha_code: "JMD",
@@ -935,6 +1132,39 @@ pub mod iso {
             symbol: "L",
             symbol_first: false,
         },
+        LTL : {
+            exponent: 2,
+            iso_alpha_code: "LTL",
+            iso_numeric_code: "440",
+            locale: EnBy,
+            minor_units: 1,
+            name: "Lithuanian Litas",
+            symbol: "Lt",
+            symbol_first: false,
+            withdrawal_date: "2015-01",
+        },
+        LUF : {
+            exponent: 0,
+            iso_alpha_code: "LUF",
+            iso_numeric_code: "442",
+            locale: EnEu,
+            minor_units: 1,
+            name: "Luxembourg Franc",
+            symbol: "F",
+            symbol_first: false,
+            withdrawal_date: "2002-03",
+        },
+        LVL : {
+            exponent: 2,
+            iso_alpha_code: "LVL",
+            iso_numeric_code: "428",
+            locale: EnBy,
+            minor_units: 1,
+            name: "Latvian Lats",
+            symbol: "Ls",
+            symbol_first: true,
+            withdrawal_date: "2014-01",
+        },
         LYD : {
             exponent: 3,
             iso_alpha_code: "LYD",
@@ -1065,6 +1295,16 @@ pub mod iso {
             symbol: "$",
             symbol_first: true,
         },
+        MXV : {
+            exponent: 2,
+            iso_alpha_code: "MXV",
+            iso_numeric_code: "979",
+            locale: EnUs,
+            minor_units: 1,
+            name: "Mexican Unidad de Inversion",
+            symbol: "",
+            symbol_first: false,
+        },
         MYR : {
             exponent: 2,
             iso_alpha_code: "MYR",
@@ -1115,6 +1355,17 @@ pub mod iso {
             symbol: "C$",
             symbol_first: true,
         },
+        NLG : {
+            exponent: 2,
+            iso_alpha_code: "NLG",
+            iso_numeric_code: "528",
+            locale: EnEu,
+            minor_units: 5,
+            name: "Dutch Guilder",
+            symbol: "ƒ",
+            symbol_first: true,
+            withdrawal_date: "2002-03",
+        },
         NOK : {
             exponent: 2,
             iso_alpha_code: "NOK",
@@ -1215,6 +1466,17 @@ pub mod iso {
             symbol: "zł",
             symbol_first: false,
         },
+        PTE : {
+            exponent: 0,
+            iso_alpha_code: "PTE",
+            iso_numeric_code: "620",
+            locale: EnEu,
+            minor_units: 1,
+            name: "Portuguese Escudo",
+            symbol: "Esc",
+            symbol_first: false,
+            withdrawal_date: "2002-03",
+        },
         PYG : {
             exponent: 0,
             iso_alpha_code: "PYG",
@@ -1254,6 +1516,7 @@ pub mod iso {
             name: "Romanian Leu",
             symbol: "ROL",
             symbol_first: false,
+            withdrawal_date: "2005-06",
         },
         RSD : {
             exponent: 2,
@@ -1364,6 +1627,7 @@ pub mod iso {
             name: "Slovak Koruna",
             symbol: "Sk",
             symbol_first: true,
+            withdrawal_date: "2009-01",
         },
         SLE : {
             exponent: 2,
@@ -1424,6 +1688,7 @@ pub mod iso {
             name: "São Tomé and Príncipe Dobra",
             symbol: "Db",
             symbol_first: false,
+            withdrawal_date: "2017-12",
         },
         STN : {
             exponent: 2,
@@ -1585,6 +1850,26 @@ pub mod iso {
             symbol: "$",
             symbol_first: true,
         },
+        USN : {
+            exponent: 2,
+            iso_alpha_code: "USN",
+            iso_numeric_code: "997",
+            locale: EnUs,
+            minor_units: 1,
+            name: "United States Dollar (Next day)",
+            symbol: "$",
+            symbol_first: true,
+        },
+        UYI : {
+            exponent: 0,
+            iso_alpha_code: "UYI",
+            iso_numeric_code: "940",
+            locale: EnEu,
+            minor_units: 1,
+            name: "Uruguay Peso en Unidades Indexadas",
+            symbol: "",
+            symbol_first: false,
+        },
         UYU : {
             exponent: 2,
             iso_alpha_code: "UYU",
@@ -1615,6 +1900,16 @@ pub mod iso {
             symbol: "so'm",
             symbol_first: false,
         },
+        VED : {
+            exponent: 2,
+            iso_alpha_code: "VED",
+            iso_numeric_code: "926",
+            locale: EnEu,
+            minor_units: 1,
+            name: "Venezuelan Bolívar Digital",
+            symbol: "Bs.D",
+            symbol_first: true,
+        },
         VES : {
             exponent: 2,
             iso_alpha_code: "VES",
@@ -1735,6 +2030,16 @@ pub mod iso {
             symbol: "$",
             symbol_first: true,
         },
+        XCG : {
+            exponent: 2,
+            iso_alpha_code: "XCG",
+            iso_numeric_code: "532",
+            locale: EnUs,
+            minor_units: 1,
+            name: "Caribbean Guilder",
+            symbol: "Cg",
+            symbol_first: false,
+        },
         XDR : {
             exponent: 0,
             iso_alpha_code: "XDR",
@@ -1785,6 +2090,16 @@ pub mod iso {
             symbol: "oz t",
             symbol_first: false,
         },
+        XSU : {
+            exponent: 0,
+            iso_alpha_code: "XSU",
+            iso_numeric_code: "994",
+            locale: EnUs,
+            minor_units: 100,
+            name: "Sucre",
+            symbol: "",
+            symbol_first: false,
+        },
         XTS : {
             exponent: 0,
             iso_alpha_code: "XTS",
@@ -1795,6 +2110,16 @@ pub mod iso {
             symbol: "oz t",
             symbol_first: false,
         },
+        XUA : {
+            exponent: 0,
+            iso_alpha_code: "XUA",
+            iso_numeric_code: "965",
+            locale: EnUs,
+            minor_units: 100,
+            name: "ADB Unit of Account",
+            symbol: "",
+            symbol_first: false,
+        },
         YER : {
             exponent: 2,
             iso_alpha_code: "YER",
@@ -1824,6 +2149,7 @@ pub mod iso {
             name: "Zambian Kwacha",
             symbol: "ZK",
             symbol_first: false,
+            withdrawal_date: "2012-12",
         },
         ZMW : {
             exponent: 2,
@@ -1835,6 +2161,16 @@ pub mod iso {
             symbol: "K",
             symbol_first: true,
         },
+        ZWG : {
+            exponent: 2,
+            iso_alpha_code: "ZWG",
+            iso_numeric_code: "924",
+            locale: EnUs,
+            minor_units: 1,
+            name: "Zimbabwe Gold",
+            symbol: "ZiG",
+            symbol_first: true,
+        },
         ZWL : {
             exponent: 2,
             iso_alpha_code: "ZWL",
@@ -1846,6 +2182,33 @@ pub mod iso {
             symbol_first: true,
         }
     );
+
+    /// Returns every currency that uses the given symbol (e.g. "$" -> USD, AUD, CAD...).
+    pub fn find_by_symbol(symbol: &str) -> Vec<&'static Currency> {
+        all()
+            .iter()
+            .filter(|currency| currency.symbol == symbol)
+            .copied()
+            .collect()
+    }
+
+    /// Returns every currency that has not been withdrawn, unlike `all` and `find`.
+    pub fn active() -> Vec<&'static Currency> {
+        all()
+            .iter()
+            .filter(|currency| currency.is_active())
+            .copied()
+            .collect()
+    }
+
+    /// Money denominated in an ISO-4217 currency.
+    pub type Money = crate::Money<'static, Currency>;
+
+    /// Creates a Money from minor units and an ISO-4217 alpha code (e.g. 1000, "USD" -> $10.00).
+    pub fn money(minor: i64, code: &str) -> Result<Money, crate::MoneyError> {
+        let currency = find(code).ok_or(crate::MoneyError::InvalidCurrency)?;
+        Ok(crate::Money::from_minor(minor, currency))
+    }
 }
 
 #[cfg(test)]
@@ -1877,8 +2240,153 @@ mod tests {
         assert_eq!(iso::USD.symbol, "$");
     }
 
+    #[test]
+    fn four_decimal_currencies_have_correct_exponent() {
+        assert_eq!(iso::CLF.exponent, 4);
+        assert_eq!(iso::UYW.exponent, 4);
+    }
+
+    #[test]
+    fn commonly_confused_currencies_have_correct_exponent() {
+        for currency in [iso::ISK, iso::HUF, iso::JPY, iso::KRW, iso::CLP, iso::VND] {
+            assert_eq!(currency.exponent, 0, "{}", currency);
+        }
+        for currency in [
+            iso::BHD,
+            iso::IQD,
+            iso::JOD,
+            iso::KWD,
+            iso::LYD,
+            iso::OMR,
+            iso::TND,
+        ] {
+            assert_eq!(currency.exponent, 3, "{}", currency);
+        }
+    }
+
+    #[test]
+    fn zero_exponent_currencies_format_without_decimals() {
+        assert_eq!(iso::money(1_000, "ISK").unwrap().to_string(), "kr.1.000");
+        assert_eq!(iso::money(1_000, "JPY").unwrap().to_string(), "¥1,000");
+    }
+
+    #[test]
+    fn with_exponent_overrides_the_table() {
+        let overrides = [("HUF", 2), ("ISK", 2)];
+        let currencies: Vec<iso::Currency> = overrides
+            .iter()
+            .map(|(code, exponent)| iso::find(code).unwrap().with_exponent(*exponent))
+            .collect();
+
+        assert_eq!(currencies[0].exponent, 2);
+        assert_eq!(currencies[0].iso_alpha_code, "HUF");
+        assert_eq!(iso::HUF.exponent, 0);
+
+        let money = crate::Money::from_minor(100_050, &currencies[1]);
+        assert_eq!(money.to_string(), "kr.1.000,50");
+    }
+
+    #[test]
+    fn withdrawn_currencies_are_inactive() {
+        assert!(iso::USD.is_active());
+        assert_eq!(iso::USD.withdrawal_date, None);
+        assert!(!iso::HRK.is_active());
+        assert_eq!(iso::HRK.withdrawal_date, Some("2023-01"));
+
+        // Withdrawn currencies can still be found, but are not listed as active
+        assert_eq!(iso::find("HRK"), Some(iso::HRK));
+        let active = iso::active();
+        assert!(active.contains(&iso::EUR));
+        assert!(!active.contains(&iso::HRK));
+        assert!(active.iter().all(|currency| currency.is_active()));
+    }
+
+    #[test]
+    fn historical_currencies_are_found_but_inactive() {
+        let dem = iso::find("DEM").unwrap();
+        assert_eq!(dem, iso::DEM);
+        assert_eq!(iso::find_by_num_code("276"), Some(iso::DEM));
+        assert_eq!(dem.withdrawal_date, Some("2002-03"));
+        assert!(!iso::active().contains(&dem));
+
+        let money = crate::Money::from_minor(150_000, iso::DEM);
+        assert_eq!(money.to_string(), "1.500,00DM");
+        let money = crate::Money::from_major(2_000, iso::ITL);
+        assert_eq!(money.to_string(), "L2.000");
+
+        for code in ["EEK", "LVL", "LTL"] {
+            assert!(!iso::find(code).unwrap().is_active());
+        }
+    }
+
+    #[test]
+    fn swiss_franc_uses_swiss_locale() {
+        assert_eq!(iso::CHF.locale, crate::Locale::DeCh);
+    }
+
+    #[test]
+    fn all_lists_every_currency() {
+        let currencies = iso::all();
+        assert_eq!(currencies.len(), 202);
+        assert!(currencies.contains(&iso::USD));
+        assert!(currencies
+            .iter()
+            .all(|currency| iso::find(currency.iso_alpha_code) == Some(*currency)));
+    }
+
+    #[test]
+    fn find_by_symbol_returns_all_matching_currencies() {
+        let dollars = iso::find_by_symbol("$");
+        assert!(dollars.len() > 1);
+        assert!(dollars.contains(&iso::USD));
+        assert!(dollars.contains(&iso::AUD));
+        assert!(dollars.iter().all(|currency| currency.symbol == "$"));
+
+        assert_eq!(iso::find_by_symbol("€"), vec![iso::EUR]);
+        assert!(iso::find_by_symbol("fake").is_empty());
+    }
+
+    #[test]
+    fn find_returns_major_and_fund_currencies() {
+        for code in [
+            "JPY", "CNY", "CAD", "CHF", "BRL", "ZAR", "KRW", "MXN", "RUB", "SEK", "NOK", "BOV",
+            "CHE", "CHW", "COU", "MXV", "USN", "UYI", "VED", "ZWG", "XCG", "XSU", "XUA",
+        ] {
+            assert_eq!(iso::find(code).unwrap().iso_alpha_code, code);
+        }
+
+        assert_eq!(iso::JPY.exponent, 0);
+        assert_eq!(iso::BHD.exponent, 3);
+        assert_eq!(iso::UYI.exponent, 0);
+        assert_eq!(iso::find_by_num_code("924"), Some(iso::ZWG));
+
+        // XCG replaced ANG and reuses its numeric code
+        assert!(!iso::ANG.is_active());
+        assert_eq!(iso::find("ANG"), Some(iso::ANG));
+        assert_eq!(iso::find_by_num_code("532"), Some(iso::XCG));
+    }
+
     #[test]
     fn find_and_reference_point_to_same() {
         assert_eq!(iso::USD, iso::find("USD").unwrap());
     }
+
+    #[test]
+    fn money_alias_and_helper_build_iso_money() {
+        let money: iso::Money = iso::money(100_050, "USD").unwrap();
+        assert_eq!(money.to_string(), "$1,000.50");
+        assert_eq!(
+            iso::money(100, "FAKE").unwrap_err(),
+            crate::MoneyError::InvalidCurrency
+        );
+    }
+
+    #[test]
+    fn money_format_with_name_uses_iso_names() {
+        let money = iso::money(100_000, "USD").unwrap();
+        assert_eq!(money.format_with_name(), "1,000.00 United States Dollar");
+
+        let money = iso::money(100_000, "EUR").unwrap();
+        assert_eq!(money.format_with_name(), "1.000,00 Euro");
+    }
 }
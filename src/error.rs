@@ -1,35 +1,179 @@
-use std::{error, fmt};
+use core::fmt;
+use core::num::ParseIntError;
+#[cfg(feature = "std")]
+use std::error;
 
 /// Standard Error type for this crate.
-#[derive(Debug, PartialEq)]
+#[derive(Debug)]
 pub enum MoneyError {
     InvalidCurrency,
-    InvalidAmount,
+    /// The amount could not be parsed, with the reason if it is known.
+    InvalidAmount(Option<AmountError>),
     InvalidRatio,
+    Overflow,
+    NoExchangeRate,
+    DivisionByZero,
+    PrecisionLoss,
+    ExcessPrecision {
+        provided: u32,
+        allowed: u32,
+    },
+    InvalidRange,
+    /// A format specification could not be parsed.
+    InvalidFormat,
 }
 
 impl fmt::Display for MoneyError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {
+        match self {
             MoneyError::InvalidCurrency => write!(f, "Currency was not valid"),
-            MoneyError::InvalidAmount => write!(f, "Amount not parsable"),
+            MoneyError::InvalidAmount(_) => write!(f, "Amount not parsable"),
             MoneyError::InvalidRatio => write!(f, "Ratio was not valid"),
+            MoneyError::Overflow => write!(f, "Amount was out of bounds"),
+            MoneyError::NoExchangeRate => write!(f, "Exchange rate was not found"),
+            MoneyError::DivisionByZero => write!(f, "Division by zero"),
+            MoneyError::PrecisionLoss => write!(f, "Amount exceeds the currency's precision"),
+            MoneyError::ExcessPrecision { provided, allowed } => write!(
+                f,
+                "Amount has {} decimal places but the currency allows {}",
+                provided, allowed
+            ),
+            MoneyError::InvalidRange => write!(f, "Range bounds were not in order"),
+            MoneyError::InvalidFormat => write!(f, "Format specification was not valid"),
         }
     }
 }
 
+/// Compares errors by variant, ignoring the reason carried by `InvalidAmount`.
+impl PartialEq for MoneyError {
+    fn eq(&self, other: &MoneyError) -> bool {
+        match (self, other) {
+            (
+                MoneyError::ExcessPrecision { provided, allowed },
+                MoneyError::ExcessPrecision {
+                    provided: other_provided,
+                    allowed: other_allowed,
+                },
+            ) => provided == other_provided && allowed == other_allowed,
+            _ => core::mem::discriminant(self) == core::mem::discriminant(other),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
 impl error::Error for MoneyError {
-    fn description(&self) -> &str {
-        match *self {
-            MoneyError::InvalidCurrency => "Currency was not valid",
-            MoneyError::InvalidAmount => "Amount not pauseable",
-            MoneyError::InvalidRatio => "Ratio was not valid",
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            MoneyError::InvalidAmount(Some(reason)) => Some(reason),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParseIntError> for MoneyError {
+    fn from(err: ParseIntError) -> MoneyError {
+        MoneyError::InvalidAmount(Some(AmountError::ParseInt(err)))
+    }
+}
+
+/// Reasons an amount could not be parsed, carried by `MoneyError::InvalidAmount`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AmountError {
+    /// The amount contains a character that is not a digit or separator.
+    NonNumeric,
+    /// The digit separators do not follow the locale's grouping pattern.
+    InvalidGrouping,
+    /// The amount contains more than one exponent separator.
+    MultipleExponentSeparators,
+    /// The amount ends with an exponent separator that has no digits after it.
+    MissingFraction,
+    /// The amount has more integer digits than a Decimal can represent.
+    TooManyDigits,
+    /// An integer in the amount could not be parsed.
+    ParseInt(ParseIntError),
+}
+
+impl fmt::Display for AmountError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AmountError::NonNumeric => write!(f, "Amount contains a non-numeric character"),
+            AmountError::InvalidGrouping => write!(f, "Digit separators are misplaced"),
+            AmountError::MultipleExponentSeparators => {
+                write!(f, "Amount has more than one exponent separator")
+            }
+            AmountError::MissingFraction => write!(f, "No digits follow the exponent separator"),
+            AmountError::TooManyDigits => write!(f, "Amount has too many digits"),
+            AmountError::ParseInt(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl error::Error for AmountError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            AmountError::ParseInt(err) => Some(err),
+            _ => None,
         }
     }
 }
 
-impl From<std::num::ParseIntError> for MoneyError {
-    fn from(_err: std::num::ParseIntError) -> MoneyError {
-        MoneyError::InvalidAmount
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "std")]
+    use std::error::Error;
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn invalid_amount_exposes_reason_as_source() {
+        let parse_error = "1x".parse::<i64>().unwrap_err();
+        let err = MoneyError::from(parse_error.clone());
+        assert_eq!(err.to_string(), "Amount not parsable");
+
+        let reason = err.source().unwrap();
+        assert_eq!(reason.to_string(), parse_error.to_string());
+        assert_eq!(
+            reason.source().unwrap().to_string(),
+            parse_error.to_string()
+        );
+
+        let err = MoneyError::InvalidAmount(Some(AmountError::TooManyDigits));
+        assert_eq!(
+            err.source().unwrap().to_string(),
+            "Amount has too many digits"
+        );
+
+        assert!(MoneyError::InvalidAmount(None).source().is_none());
+        assert!(MoneyError::InvalidCurrency.source().is_none());
+    }
+
+    #[test]
+    fn equality_ignores_invalid_amount_reason() {
+        assert_eq!(
+            MoneyError::InvalidAmount(Some(AmountError::NonNumeric)),
+            MoneyError::InvalidAmount(None)
+        );
+        assert_ne!(MoneyError::InvalidAmount(None), MoneyError::InvalidRatio);
+        assert_eq!(
+            MoneyError::ExcessPrecision {
+                provided: 3,
+                allowed: 2
+            },
+            MoneyError::ExcessPrecision {
+                provided: 3,
+                allowed: 2
+            }
+        );
+        assert_ne!(
+            MoneyError::ExcessPrecision {
+                provided: 3,
+                allowed: 2
+            },
+            MoneyError::ExcessPrecision {
+                provided: 4,
+                allowed: 2
+            }
+        );
     }
 }
@@ -1,7 +1,9 @@
 use crate::currency::FormattableCurrency;
-use crate::{Money, MoneyError};
+use crate::{Money, MoneyError, Round};
 use rust_decimal::Decimal;
+use std::cmp::Ordering;
 use std::collections::HashMap;
+use std::time::{Duration, SystemTime};
 
 /// Stores `ExchangeRate`s for easier access.
 #[derive(Debug, Default)]
@@ -16,10 +18,61 @@ impl<'a, T: FormattableCurrency> Exchange<'a, T> {
         }
     }
 
+    /// Creates an Exchange from a square matrix of rates.
+    ///
+    /// `matrix[i][j]` is the rate from `currencies[i]` to `currencies[j]`. The diagonal is ignored.
+    /// Errors with `MoneyError::InvalidRatio` if the matrix is not N x N or contains a rate that is
+    /// zero or negative.
+    pub fn from_matrix(
+        currencies: &[&'a T],
+        matrix: &[Vec<Decimal>],
+    ) -> Result<Exchange<'a, T>, MoneyError> {
+        if matrix.len() != currencies.len()
+            || matrix.iter().any(|row| row.len() != currencies.len())
+        {
+            return Err(MoneyError::InvalidRatio);
+        }
+
+        let mut exchange = Exchange::new();
+        for (i, &from) in currencies.iter().enumerate() {
+            for (j, &to) in currencies.iter().enumerate() {
+                if i == j {
+                    continue;
+                }
+                let rate = matrix[i][j];
+                if rate <= Decimal::ZERO {
+                    return Err(MoneyError::InvalidRatio);
+                }
+                exchange.set_rate(&ExchangeRate::new(from, to, rate)?);
+            }
+        }
+        Ok(exchange)
+    }
+
     /// Update an ExchangeRate or add it if does not exist.
     pub fn set_rate(&mut self, rate: &ExchangeRate<'a, T>) {
+        self.insert_rate(*rate);
+    }
+
+    /// Update an ExchangeRate or add it if does not exist, taking the rate by value so it can be
+    /// built inline.
+    pub fn insert_rate(&mut self, rate: ExchangeRate<'a, T>) {
         let key = Exchange::generate_key(rate.from, rate.to);
-        self.map.insert(key, *rate);
+        self.map.insert(key, rate);
+    }
+
+    /// Update an ExchangeRate and its inverse, or add them if they do not exist.
+    ///
+    /// Returns the stored inverse rate, or `MoneyError::DivisionByZero` without storing either
+    /// rate if the rate is zero.
+    pub fn set_rate_and_inverse(
+        &mut self,
+        rate: &ExchangeRate<'a, T>,
+    ) -> Result<ExchangeRate<'a, T>, MoneyError> {
+        let inverse = rate.invert()?;
+        self.insert_rate(*rate);
+        self.insert_rate(inverse);
+        Ok(inverse)
     }
 
     /// Return the ExchangeRate given the currency pair.
@@ -28,6 +81,136 @@ impl<'a, T: FormattableCurrency> Exchange<'a, T> {
         self.map.get(&key).copied()
     }
 
+    /// Return the ExchangeRate given the currency pair, if it is no older than `max_age`.
+    ///
+    /// Rates created without a timestamp are always considered fresh.
+    pub fn get_rate_if_fresh(
+        &self,
+        from: &T,
+        to: &T,
+        max_age: Duration,
+    ) -> Option<ExchangeRate<'a, T>> {
+        self.get_rate(from, to).filter(|rate| match rate.timestamp {
+            Some(timestamp) => match SystemTime::now().duration_since(timestamp) {
+                Ok(age) => age <= max_age,
+                // Quoted in the future, most likely due to clock skew.
+                Err(_) => true,
+            },
+            None => true,
+        })
+    }
+
+    /// Replaces every stored rate with the result of applying `f` to its currency pair and rate.
+    pub fn update_all<F: FnMut(&T, &T, Decimal) -> Decimal>(&mut self, mut f: F) {
+        for rate in self.map.values_mut() {
+            rate.rate = f(rate.from, rate.to, rate.rate);
+        }
+    }
+
+    /// Adds two Money objects after converting both into the target Currency.
+    ///
+    /// The sum is rounded to the target Currency's exponent using the given strategy.
+    pub fn add(
+        &self,
+        a: &Money<'a, T>,
+        b: &Money<'a, T>,
+        in_currency: &'a T,
+        round: Round,
+    ) -> Result<Money<'a, T>, MoneyError> {
+        let sum = self.convert(a, in_currency)? + self.convert(b, in_currency)?;
+        Ok(sum.round(in_currency.exponent(), round))
+    }
+
+    /// Compares two Money objects after converting both into the base Currency.
+    ///
+    /// Unlike `Ord`, this works across currencies, erroring if a rate to the base is missing.
+    pub fn compare(
+        &self,
+        a: &Money<'a, T>,
+        b: &Money<'a, T>,
+        base: &'a T,
+    ) -> Result<Ordering, MoneyError> {
+        let a = self.convert(a, base)?;
+        let b = self.convert(b, base)?;
+        Ok(a.amount().cmp(b.amount()))
+    }
+
+    /// Converts a Money into the target Currency and allocates it by the given ratios.
+    ///
+    /// The converted amount is rounded to the target Currency's exponent and split into whole minor
+    /// units, so the shares always sum to the rounded converted total.
+    pub fn convert_and_allocate(
+        &self,
+        amount: &Money<'a, T>,
+        to: &'a T,
+        ratios: Vec<i32>,
+        round: Round,
+    ) -> Result<Vec<Money<'a, T>>, MoneyError> {
+        let converted = self.convert(amount, to)?.round_to_currency(round);
+
+        // Allocate in minor units, since Money::allocate distributes whole major units.
+        let scale = converted.minor_unit_scale()?;
+        let minor_units = Money::from_decimal(converted.amount() * scale, to);
+        let shares = minor_units.allocate(ratios)?;
+        Ok(shares
+            .into_iter()
+            .map(|share| Money::from_decimal(share.amount() / scale, to))
+            .collect())
+    }
+
+    /// Converts each Money into the target Currency, failing if any conversion fails.
+    ///
+    /// Money already in the target Currency is kept as is. Errors with `MoneyError::NoExchangeRate`
+    /// if a rate is missing for any of the currencies.
+    pub fn convert_all(
+        &self,
+        amounts: &[Money<'a, T>],
+        to: &'a T,
+    ) -> Result<Vec<Money<'a, T>>, MoneyError> {
+        amounts
+            .iter()
+            .map(|amount| self.convert(amount, to))
+            .collect()
+    }
+
+    /// Converts each Money into the target Currency, returning a Result per item.
+    ///
+    /// Unlike a single conversion, a missing rate only fails the items it applies to.
+    pub fn convert_all_partial(
+        &self,
+        amounts: &[Money<'a, T>],
+        to: &'a T,
+    ) -> Vec<Result<Money<'a, T>, MoneyError>> {
+        amounts
+            .iter()
+            .map(|amount| self.convert(amount, to))
+            .collect()
+    }
+
+    /// Lazily converts each Money of an iterator into the target Currency, yielding a Result per item.
+    pub fn convert_iter<'e, I: Iterator<Item = Money<'a, T>> + 'e>(
+        &'e self,
+        iter: I,
+        to: &'a T,
+    ) -> impl Iterator<Item = Result<Money<'a, T>, MoneyError>> + 'e {
+        iter.map(move |amount| self.convert(&amount, to))
+    }
+
+    /// Converts a Money into the target Currency using the stored rate, if one exists.
+    pub(crate) fn convert(
+        &self,
+        amount: &Money<'a, T>,
+        to: &'a T,
+    ) -> Result<Money<'a, T>, MoneyError> {
+        if amount.currency() == to {
+            return Ok(*amount);
+        }
+        match self.get_rate(amount.currency(), to) {
+            Some(rate) => rate.convert(amount),
+            None => Err(MoneyError::NoExchangeRate),
+        }
+    }
+
     fn generate_key(from: &T, to: &T) -> String {
         from.to_string() + "-" + &to.to_string()
     }
@@ -39,6 +222,7 @@ pub struct ExchangeRate<'a, T: FormattableCurrency> {
     pub from: &'a T,
     pub to: &'a T,
     rate: Decimal,
+    timestamp: Option<SystemTime>,
 }
 
 impl<'a, T: FormattableCurrency> ExchangeRate<'a, T> {
@@ -46,7 +230,53 @@ impl<'a, T: FormattableCurrency> ExchangeRate<'a, T> {
         if from == to {
             return Err(MoneyError::InvalidCurrency);
         }
-        Ok(ExchangeRate { from, to, rate })
+        Ok(ExchangeRate {
+            from,
+            to,
+            rate,
+            timestamp: None,
+        })
+    }
+
+    /// Creates an ExchangeRate that records the time at which it was quoted.
+    pub fn with_timestamp(
+        from: &'a T,
+        to: &'a T,
+        rate: Decimal,
+        timestamp: SystemTime,
+    ) -> Result<ExchangeRate<'a, T>, MoneyError> {
+        let mut exchange_rate = ExchangeRate::new(from, to, rate)?;
+        exchange_rate.timestamp = Some(timestamp);
+        Ok(exchange_rate)
+    }
+
+    /// Returns the rate of conversion (e.g. 0.85 when 1 USD converts to 0.85 EUR).
+    pub fn rate(&self) -> Decimal {
+        self.rate
+    }
+
+    /// Returns the time at which the rate was quoted, if known.
+    pub fn timestamp(&self) -> Option<SystemTime> {
+        self.timestamp
+    }
+
+    /// Returns the reciprocal rate, converting from `to` back to `from` (e.g. USD/EUR at 0.5 ->
+    /// EUR/USD at 2), with the same timestamp.
+    ///
+    /// Errors with `MoneyError::DivisionByZero` if the rate is zero.
+    pub fn invert(&self) -> Result<ExchangeRate<'a, T>, MoneyError> {
+        if self.rate.is_zero() {
+            return Err(MoneyError::DivisionByZero);
+        }
+        let rate = Decimal::ONE
+            .checked_div(self.rate)
+            .ok_or(MoneyError::Overflow)?;
+        Ok(ExchangeRate {
+            from: self.to,
+            to: self.from,
+            rate,
+            timestamp: self.timestamp,
+        })
     }
 
     /// Converts a Money from one Currency to another using the exchange rate.
@@ -57,6 +287,22 @@ impl<'a, T: FormattableCurrency> ExchangeRate<'a, T> {
         let converted_amount = amount.amount() * self.rate;
         Ok(Money::from_decimal(converted_amount, self.to))
     }
+
+    /// Converts a Money using the exchange rate, rounding half-even to the target currency's exponent.
+    pub fn convert_rounded(&self, amount: &Money<'a, T>) -> Result<Money<'a, T>, MoneyError> {
+        self.convert_rounded_with(amount, Round::HalfEven)
+    }
+
+    /// Converts a Money using the exchange rate, rounding to the target currency's exponent using
+    /// the rounding strategy.
+    pub fn convert_rounded_with(
+        &self,
+        amount: &Money<'a, T>,
+        strategy: Round,
+    ) -> Result<Money<'a, T>, MoneyError> {
+        let converted = self.convert(amount)?;
+        Ok(converted.round_to_currency(strategy))
+    }
 }
 
 #[cfg(test)]
@@ -117,6 +363,307 @@ mod tests {
         assert_eq!(fetched_rate.rate, dec!(1.6));
     }
 
+    #[test]
+    fn exchange_insert_rate_takes_rate_by_value() {
+        let mut exchange = Exchange::new();
+        exchange.insert_rate(ExchangeRate::new(test::USD, test::EUR, dec!(0.9)).unwrap());
+        assert_eq!(
+            exchange.get_rate(test::USD, test::EUR).unwrap().rate(),
+            dec!(0.9)
+        );
+
+        // Inserting the same pair replaces the rate
+        exchange.insert_rate(ExchangeRate::new(test::USD, test::EUR, dec!(0.8)).unwrap());
+        assert_eq!(
+            exchange.get_rate(test::USD, test::EUR).unwrap().rate(),
+            dec!(0.8)
+        );
+    }
+
+    #[test]
+    fn exchange_set_rate_and_inverse() {
+        let mut exchange = Exchange::new();
+        let rate = ExchangeRate::new(test::USD, test::EUR, dec!(0.8)).unwrap();
+        let inverse = exchange.set_rate_and_inverse(&rate).unwrap();
+        assert_eq!(inverse.from, test::EUR);
+        assert_eq!(inverse.to, test::USD);
+
+        assert_eq!(exchange.get_rate(test::USD, test::EUR), Some(rate));
+        let fetched = exchange.get_rate(test::EUR, test::USD).unwrap();
+        assert_eq!(fetched, inverse);
+        assert_eq!(
+            fetched.convert(&Money::from_minor(800, test::EUR)),
+            Ok(Money::from_minor(1_000, test::USD))
+        );
+
+        let zero = ExchangeRate::new(test::USD, test::GBP, dec!(0)).unwrap();
+        assert_eq!(
+            exchange.set_rate_and_inverse(&zero),
+            Err(MoneyError::DivisionByZero)
+        );
+        assert_eq!(exchange.get_rate(test::USD, test::GBP), None);
+    }
+
+    #[test]
+    fn exchange_convert_all() {
+        let mut exchange = Exchange::new();
+        exchange.set_rate(&ExchangeRate::new(test::USD, test::EUR, dec!(0.5)).unwrap());
+        exchange.set_rate(&ExchangeRate::new(test::GBP, test::EUR, dec!(1.2)).unwrap());
+
+        let portfolio = vec![
+            Money::from_minor(1_000, test::USD),
+            Money::from_minor(1_000, test::GBP),
+            Money::from_minor(1_000, test::EUR),
+        ];
+        assert_eq!(
+            exchange.convert_all(&portfolio, test::EUR),
+            Ok(vec![
+                Money::from_minor(500, test::EUR),
+                Money::from_minor(1_200, test::EUR),
+                Money::from_minor(1_000, test::EUR),
+            ])
+        );
+
+        // A single missing rate fails the whole conversion
+        assert_eq!(
+            exchange.convert_all(&portfolio, test::USD),
+            Err(MoneyError::NoExchangeRate)
+        );
+        assert_eq!(exchange.convert_all(&[], test::USD), Ok(vec![]));
+    }
+
+    #[test]
+    fn exchange_convert_all_partial_returns_result_per_item() {
+        let mut exchange = Exchange::new();
+        exchange.set_rate(&ExchangeRate::new(test::USD, test::EUR, dec!(0.5)).unwrap());
+
+        let amounts = vec![
+            Money::from_minor(1_000, test::USD),
+            Money::from_minor(1_000, test::GBP),
+            Money::from_minor(1_000, test::EUR),
+        ];
+        let converted = exchange.convert_all_partial(&amounts, test::EUR);
+        assert_eq!(
+            converted,
+            vec![
+                Ok(Money::from_minor(500, test::EUR)),
+                Err(MoneyError::NoExchangeRate),
+                Ok(Money::from_minor(1_000, test::EUR)),
+            ]
+        );
+    }
+
+    #[test]
+    fn exchange_convert_iter_converts_lazily() {
+        let mut exchange = Exchange::new();
+        exchange.set_rate(&ExchangeRate::new(test::USD, test::GBP, dec!(0.75)).unwrap());
+        exchange.set_rate(&ExchangeRate::new(test::EUR, test::GBP, dec!(0.85)).unwrap());
+
+        let amounts = (1..=3).map(|i| {
+            if i % 2 == 0 {
+                Money::from_minor(i * 1_000, test::EUR)
+            } else {
+                Money::from_minor(i * 1_000, test::USD)
+            }
+        });
+        let converted: Result<Vec<_>, _> = exchange.convert_iter(amounts, test::GBP).collect();
+        assert_eq!(
+            converted.unwrap(),
+            vec![
+                Money::from_minor(750, test::GBP),
+                Money::from_minor(1_700, test::GBP),
+                Money::from_minor(2_250, test::GBP),
+            ]
+        );
+
+        // Items are converted on demand, so a missing rate only fails when reached
+        let amounts = vec![
+            Money::from_minor(1_000, test::EUR),
+            Money::from_minor(1_000, test::USD),
+        ];
+        let mut converted = exchange.convert_iter(amounts.into_iter(), test::EUR);
+        assert_eq!(
+            converted.next(),
+            Some(Ok(Money::from_minor(1_000, test::EUR)))
+        );
+        assert_eq!(converted.next(), Some(Err(MoneyError::NoExchangeRate)));
+        assert_eq!(converted.next(), None);
+    }
+
+    #[test]
+    fn exchange_get_rate_if_fresh_rejects_stale_rates() {
+        let hour = Duration::from_secs(3_600);
+        let quoted_at = SystemTime::now() - 2 * hour;
+
+        let mut exchange = Exchange::new();
+        exchange.set_rate(
+            &ExchangeRate::with_timestamp(test::USD, test::EUR, dec!(1.5), quoted_at).unwrap(),
+        );
+        exchange.set_rate(&ExchangeRate::new(test::USD, test::GBP, dec!(1.6)).unwrap());
+
+        let rate = exchange.get_rate(test::USD, test::EUR).unwrap();
+        assert_eq!(rate.timestamp(), Some(quoted_at));
+
+        assert!(exchange
+            .get_rate_if_fresh(test::USD, test::EUR, hour)
+            .is_none());
+        assert!(exchange
+            .get_rate_if_fresh(test::USD, test::EUR, 3 * hour)
+            .is_some());
+
+        // Rates without a timestamp never expire
+        assert!(exchange
+            .get_rate_if_fresh(test::USD, test::GBP, Duration::ZERO)
+            .is_some());
+    }
+
+    #[test]
+    fn exchange_update_all_adjusts_every_rate() {
+        let mut exchange = Exchange::new();
+        exchange.set_rate(&ExchangeRate::new(test::USD, test::EUR, dec!(1.5)).unwrap());
+        exchange.set_rate(&ExchangeRate::new(test::USD, test::GBP, dec!(2)).unwrap());
+
+        // Widen every rate by 1%
+        exchange.update_all(|_, _, rate| rate * dec!(1.01));
+
+        let fetched_rate = exchange.get_rate(test::USD, test::EUR).unwrap();
+        assert_eq!(fetched_rate.rate, dec!(1.515));
+
+        let fetched_rate = exchange.get_rate(test::USD, test::GBP).unwrap();
+        assert_eq!(fetched_rate.rate, dec!(2.02));
+    }
+
+    #[test]
+    fn exchange_add_sums_in_target_currency() {
+        let mut exchange = Exchange::new();
+        exchange.set_rate(&ExchangeRate::new(test::USD, test::GBP, dec!(0.8)).unwrap());
+        exchange.set_rate(&ExchangeRate::new(test::EUR, test::GBP, dec!(0.857)).unwrap());
+
+        let usd = Money::from_major(10, test::USD);
+        let eur = Money::from_major(5, test::EUR);
+
+        // 8.00 GBP + 4.285 GBP
+        let total = exchange.add(&usd, &eur, test::GBP, Round::HalfEven);
+        assert_eq!(total.unwrap(), Money::from_minor(1_228, test::GBP));
+
+        let total = exchange.add(&usd, &eur, test::GBP, Round::HalfUp);
+        assert_eq!(total.unwrap(), Money::from_minor(1_229, test::GBP));
+
+        // Operands already in the target currency are not converted
+        let gbp = Money::from_major(1, test::GBP);
+        let total = exchange.add(&usd, &gbp, test::GBP, Round::HalfEven);
+        assert_eq!(total.unwrap(), Money::from_minor(900, test::GBP));
+
+        let total = exchange.add(&usd, &eur, test::USD, Round::HalfEven);
+        assert_eq!(total.unwrap_err(), MoneyError::NoExchangeRate);
+    }
+
+    #[test]
+    fn exchange_compare_across_currencies() {
+        let mut exchange = Exchange::new();
+        exchange.set_rate(&ExchangeRate::new(test::EUR, test::USD, dec!(1.1)).unwrap());
+
+        // 5 EUR is 5.50 USD
+        let eur = Money::from_major(5, test::EUR);
+        let usd = Money::from_major(4, test::USD);
+        assert_eq!(
+            exchange.compare(&eur, &usd, test::USD),
+            Ok(Ordering::Greater)
+        );
+        assert_eq!(exchange.compare(&usd, &eur, test::USD), Ok(Ordering::Less));
+
+        let usd = Money::from_minor(550, test::USD);
+        assert_eq!(exchange.compare(&eur, &usd, test::USD), Ok(Ordering::Equal));
+
+        let usd = Money::from_major(6, test::USD);
+        assert_eq!(exchange.compare(&eur, &usd, test::USD), Ok(Ordering::Less));
+
+        let gbp = Money::from_major(1, test::GBP);
+        assert_eq!(
+            exchange.compare(&eur, &gbp, test::USD),
+            Err(MoneyError::NoExchangeRate)
+        );
+    }
+
+    #[test]
+    fn exchange_convert_and_allocate() {
+        let mut exchange = Exchange::new();
+        exchange.set_rate(&ExchangeRate::new(test::USD, test::EUR, dec!(0.857)).unwrap());
+
+        let usd = Money::from_major(100, test::USD);
+        let shares = exchange
+            .convert_and_allocate(&usd, test::EUR, vec![1, 1, 1], Round::HalfEven)
+            .unwrap();
+        assert_eq!(
+            shares,
+            vec![
+                Money::from_minor(2_857, test::EUR),
+                Money::from_minor(2_857, test::EUR),
+                Money::from_minor(2_856, test::EUR),
+            ]
+        );
+
+        let total = shares
+            .into_iter()
+            .fold(Money::from_major(0, test::EUR), |sum, share| sum + share);
+        assert_eq!(total, Money::from_minor(8_570, test::EUR));
+
+        let result = exchange.convert_and_allocate(&usd, test::GBP, vec![1, 1], Round::HalfEven);
+        assert_eq!(result.unwrap_err(), MoneyError::NoExchangeRate);
+
+        let result = exchange.convert_and_allocate(&usd, test::EUR, vec![], Round::HalfEven);
+        assert_eq!(result.unwrap_err(), MoneyError::InvalidRatio);
+    }
+
+    #[test]
+    fn exchange_from_matrix() {
+        let currencies = [test::USD, test::EUR, test::GBP];
+        let matrix = vec![
+            vec![dec!(1), dec!(0.9), dec!(0.8)],
+            vec![dec!(1.1), dec!(1), dec!(0.85)],
+            vec![dec!(1.25), dec!(1.18), dec!(1)],
+        ];
+        let exchange = Exchange::from_matrix(&currencies, &matrix).unwrap();
+
+        assert_eq!(
+            exchange.get_rate(test::USD, test::EUR).unwrap().rate,
+            dec!(0.9)
+        );
+        assert_eq!(
+            exchange.get_rate(test::EUR, test::GBP).unwrap().rate,
+            dec!(0.85)
+        );
+        assert_eq!(
+            exchange.get_rate(test::GBP, test::USD).unwrap().rate,
+            dec!(1.25)
+        );
+        assert!(exchange.get_rate(test::USD, test::USD).is_none());
+    }
+
+    #[test]
+    fn exchange_from_matrix_validates_input() {
+        let currencies = [test::USD, test::EUR];
+
+        // Wrong number of rows
+        let matrix = vec![vec![dec!(1), dec!(0.9)]];
+        let exchange = Exchange::from_matrix(&currencies, &matrix);
+        assert_eq!(exchange.unwrap_err(), MoneyError::InvalidRatio);
+
+        // Wrong number of columns
+        let matrix = vec![vec![dec!(1), dec!(0.9)], vec![dec!(1.1)]];
+        let exchange = Exchange::from_matrix(&currencies, &matrix);
+        assert_eq!(exchange.unwrap_err(), MoneyError::InvalidRatio);
+
+        // Zero and negative rates
+        let matrix = vec![vec![dec!(1), dec!(0)], vec![dec!(1.1), dec!(1)]];
+        let exchange = Exchange::from_matrix(&currencies, &matrix);
+        assert_eq!(exchange.unwrap_err(), MoneyError::InvalidRatio);
+
+        let matrix = vec![vec![dec!(1), dec!(0.9)], vec![dec!(-1.1), dec!(1)]];
+        let exchange = Exchange::from_matrix(&currencies, &matrix);
+        assert_eq!(exchange.unwrap_err(), MoneyError::InvalidRatio);
+    }
+
     #[test]
     fn rate_convert() {
         let rate = ExchangeRate::new(test::USD, test::EUR, dec!(1.5)).unwrap();
@@ -126,6 +673,63 @@ mod tests {
         assert_eq!(converted_rate, expected_amount);
     }
 
+    #[test]
+    fn rate_convert_rounded() {
+        let rate = ExchangeRate::new(test::USD, test::EUR, dec!(0.857)).unwrap();
+        let amount = Money::from_minor(1_000, test::USD);
+
+        let converted = rate.convert_rounded(&amount).unwrap();
+        assert_eq!(converted, Money::from_minor(857, test::EUR));
+        assert_eq!(converted.amount().scale(), 2);
+
+        // The raw conversion keeps full precision for chaining
+        let converted = rate.convert(&Money::from_minor(1_005, test::USD)).unwrap();
+        assert_eq!(*converted.amount(), dec!(8.612850));
+
+        let rate = ExchangeRate::new(test::USD, test::EUR, dec!(0.5)).unwrap();
+        let amount = Money::from_minor(1_025, test::USD);
+        assert_eq!(
+            rate.convert_rounded(&amount).unwrap(),
+            Money::from_minor(512, test::EUR)
+        );
+        assert_eq!(
+            rate.convert_rounded_with(&amount, Round::HalfUp).unwrap(),
+            Money::from_minor(513, test::EUR)
+        );
+        assert_eq!(
+            rate.convert_rounded(&Money::from_minor(1_000, test::GBP))
+                .unwrap_err(),
+            MoneyError::InvalidCurrency
+        );
+    }
+
+    #[test]
+    fn rate_getter_returns_rate() {
+        let rate = ExchangeRate::new(test::USD, test::EUR, dec!(0.85)).unwrap();
+        assert_eq!(rate.rate(), dec!(0.85));
+    }
+
+    #[test]
+    fn rate_invert() {
+        let rate = ExchangeRate::new(test::USD, test::EUR, dec!(0.5)).unwrap();
+        let inverse = rate.invert().unwrap();
+        assert_eq!(inverse.from, test::EUR);
+        assert_eq!(inverse.to, test::USD);
+        assert_eq!(inverse.rate, dec!(2));
+        assert_eq!(
+            inverse.convert(&Money::from_minor(1_000, test::EUR)),
+            Ok(Money::from_minor(2_000, test::USD))
+        );
+        assert_eq!(inverse.invert().unwrap().rate, dec!(0.5));
+
+        let quoted_at = SystemTime::UNIX_EPOCH + Duration::from_secs(60);
+        let rate = ExchangeRate::with_timestamp(test::USD, test::EUR, dec!(4), quoted_at).unwrap();
+        assert_eq!(rate.invert().unwrap().timestamp(), Some(quoted_at));
+
+        let rate = ExchangeRate::new(test::USD, test::EUR, dec!(0)).unwrap();
+        assert_eq!(rate.invert(), Err(MoneyError::DivisionByZero));
+    }
+
     #[test]
     fn rate_convert_errors_if_currencies_do_not_match() {
         let rate = ExchangeRate::new(test::GBP, test::EUR, dec!(1.5)).unwrap();
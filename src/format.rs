@@ -1,6 +1,9 @@
 use crate::currency::FormattableCurrency;
-use crate::{Money, Round};
-use std::cmp::Ordering;
+use crate::{LocalFormat, Money, MoneyError, Round};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
+use rust_decimal::Decimal;
 
 /// Converts Money objects into human readable strings.
 pub struct Formatter;
@@ -15,6 +18,19 @@ impl<'a> Formatter {
             decimal = *money.round(x, Round::HalfEven).amount();
         }
 
+        // Show at least min_fraction_digits, and up to max_fraction_digits when they are not zero
+        if params.min_fraction_digits.is_some() || params.max_fraction_digits.is_some() {
+            if let Some(max) = params.max_fraction_digits {
+                decimal = decimal.round_dp(max);
+            }
+            decimal = decimal.normalize();
+            if let Some(min) = params.min_fraction_digits {
+                if decimal.scale() < min {
+                    decimal.rescale(min);
+                }
+            }
+        }
+
         // Format the Amount String
         let amount = Formatter::amount(&format!("{}", decimal), &params);
 
@@ -23,70 +39,211 @@ impl<'a> Formatter {
         for position in params.positions.iter() {
             match position {
                 Position::Space => result.push(' '),
+                Position::Spaces(count) => result.push_str(&" ".repeat(*count)),
                 Position::Amount => result.push_str(&amount),
                 Position::Code => result.push_str(params.code.unwrap_or("")),
                 Position::Symbol => result.push_str(params.symbol.unwrap_or("")),
-                Position::Sign => result.push_str(if money.is_negative() { "-" } else { "" }),
+                Position::Name => result.push_str(params.name.unwrap_or("")),
+                Position::Sign => {
+                    if money.is_negative() && !params.accounting {
+                        result.push_str(params.negative_symbol.unwrap_or("-"));
+                    }
+                }
             }
         }
+
+        if params.accounting && money.is_negative() {
+            result = format!("({})", result);
+        }
         result
     }
 
     /// Returns a formatted amount String, given the raw amount and formatting parameters.
     fn amount(raw_amount: &str, params: &Params) -> String {
         // Split amount into digits and exponent.
-        let amount_split: Vec<&str> = raw_amount.split('.').collect();
-        let mut amount_digits = amount_split[0].to_string();
+        let (digits, exponent) = match raw_amount.split_once('.') {
+            Some((digits, exponent)) => (digits, Some(exponent)),
+            None => (raw_amount, None),
+        };
 
         // Format the digits
+        let mut amount_digits = digits.to_string();
         amount_digits.retain(|c| c != '-');
-        amount_digits = Formatter::digits(
+        let mut result = Formatter::digits(
             &amount_digits,
             params.digit_separator,
             &params.separator_pattern,
         );
-        let mut result = amount_digits;
 
         // Format the exponent, and add to digits
-        match amount_split.len().cmp(&2) {
-            Ordering::Equal => {
-                // Exponent found, concatenate to digits.
+        if let Some(exponent) = exponent {
+            if !(params.trim_trailing_zeros && exponent.chars().all(|c| c == '0')) {
                 result.push(params.exponent_separator);
-                result += amount_split[1];
-            }
-            Ordering::Less => {
-                // No exponent, do nothing.
+                result += exponent;
             }
-            Ordering::Greater => panic!("More than 1 exponent separators when parsing Decimal"),
         }
 
         result
     }
 
+    /// Returns the Position that a character outside the digits of a format specification stands for.
+    fn dsl_position(c: char) -> Result<Position, MoneyError> {
+        match c {
+            'S' => Ok(Position::Symbol),
+            'C' => Ok(Position::Code),
+            ' ' => Ok(Position::Space),
+            _ => Err(MoneyError::InvalidFormat),
+        }
+    }
+
     /// Returns a formatted digit component, given the digit string, separator and pattern of separation.
     fn digits(raw_digits: &str, separator: char, pattern: &[usize]) -> String {
         let mut digits = raw_digits.to_string();
 
         let mut current_position: usize = 0;
         for position in pattern.iter() {
-            current_position += position;
+            current_position = current_position.saturating_add(*position);
             if digits.len() > current_position {
                 digits.insert(digits.len() - current_position, separator);
-                current_position += 1;
+                current_position += separator.len_utf8();
             }
         }
         digits
     }
 }
 
+/// Returns a Decimal formatted with the separators of a LocalFormat, without any currency.
+///
+/// The amount is rounded half-even and padded to exactly the given number of decimals
+/// (e.g. 1234567.891 with EnUs and 2 decimals -> "1,234,567.89").
+pub fn format_decimal(amount: Decimal, format: &LocalFormat, decimals: u32) -> String {
+    let params = Params {
+        digit_separator: format.digit_separator,
+        exponent_separator: format.exponent_separator,
+        separator_pattern: format.digit_separator_pattern(),
+        ..Default::default()
+    };
+    let rounded = amount.round_dp(decimals);
+    let digits = Formatter::amount(&format!("{:.*}", decimals as usize, rounded), &params);
+
+    match (
+        rounded.is_sign_negative() && !rounded.is_zero(),
+        format.trailing_sign,
+    ) {
+        (false, _) => digits,
+        (true, false) => format!("-{}", digits),
+        (true, true) => format!("{}-", digits),
+    }
+}
+
+impl Params {
+    /// Builds Params from a compact format specification (e.g. "S#.###,##").
+    ///
+    /// `#` marks digits, and `S` and `C` place the symbol and code before or after them, with
+    /// spaces kept as written. Separators are single characters that are not letters or digits.
+    /// The last separator between digits is the exponent separator and the digits after it set
+    /// the rounding; any earlier separator groups digits into runs the size of the last integer
+    /// run. A trailing separator sets the exponent separator without decimals (e.g. "#,###.").
+    pub fn from_dsl(spec: &str) -> Result<Params, MoneyError> {
+        let start = spec.find('#').ok_or(MoneyError::InvalidFormat)?;
+        let mut end = spec.rfind('#').ok_or(MoneyError::InvalidFormat)? + 1;
+
+        // A separator directly after the last digit sets the exponent separator with no decimals
+        let mut trailing = None;
+        if let Some(c) = spec[end..].chars().next() {
+            if !c.is_alphanumeric() && c != ' ' {
+                trailing = Some(c);
+                end += c.len_utf8();
+            }
+        }
+
+        let mut positions = vec![Position::Sign];
+        for c in spec[..start].chars() {
+            positions.push(Formatter::dsl_position(c)?);
+        }
+        positions.push(Position::Amount);
+        for c in spec[end..].chars() {
+            positions.push(Formatter::dsl_position(c)?);
+        }
+
+        // Split the digits into runs of # and the single separators between them
+        let mut runs = vec![0];
+        let mut separators = Vec::new();
+        for c in spec[start..end - trailing.map_or(0, char::len_utf8)].chars() {
+            match c {
+                '#' => *runs.last_mut().unwrap() += 1,
+                _ if c.is_alphanumeric() || *runs.last().unwrap() == 0 => {
+                    return Err(MoneyError::InvalidFormat)
+                }
+                _ => {
+                    separators.push(c);
+                    runs.push(0);
+                }
+            }
+        }
+
+        let (exponent_separator, decimals) = match trailing {
+            Some(separator) => (Some(separator), 0),
+            None if !separators.is_empty() => (separators.pop(), runs.pop().unwrap()),
+            None => (None, 0),
+        };
+
+        let mut params = Params {
+            positions,
+            rounding: Some(decimals as u32),
+            separator_pattern: Vec::new(),
+            ..Default::default()
+        };
+        if let Some(separator) = exponent_separator {
+            params.exponent_separator = separator;
+        }
+
+        if let Some(&digit_separator) = separators.first() {
+            let group = *runs.last().unwrap();
+            let first = runs[0];
+            if separators.iter().any(|&c| c != digit_separator)
+                || Some(digit_separator) == exponent_separator
+                || first > group
+                || runs[1..].iter().any(|&run| run != group)
+            {
+                return Err(MoneyError::InvalidFormat);
+            }
+
+            // Enough groups for the 28 integer digits a Decimal can hold
+            params.digit_separator = digit_separator;
+            params.separator_pattern = vec![group; 28 / group];
+        }
+        Ok(params)
+    }
+}
+
+/// Renders Money as a String, allowing custom output to be used with `Money::to_string_with`.
+pub trait MoneyFormatter {
+    fn format<T: FormattableCurrency>(&self, money: &Money<T>) -> String;
+}
+
+/// Formats Money using the Locale of its currency, producing the same output as Display.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LocaleFormatter;
+
+impl MoneyFormatter for LocaleFormatter {
+    fn format<T: FormattableCurrency>(&self, money: &Money<T>) -> String {
+        money.format_with(&LocalFormat::from_locale(money.currency().locale()))
+    }
+}
+
 /// Items which must be positioned in a Money string.
 #[derive(Debug, Clone)]
 pub enum Position {
+    /// A single space.
     Space,
+    /// The given number of spaces.
+    Spaces(usize),
     Amount,
     Code,
     Symbol,
     Sign,
+    Name,
 }
 
 /// Group of formatting parameters consumed by `Formatter`.
@@ -106,6 +263,18 @@ pub struct Params {
     pub symbol: Option<&'static str>,
     /// The currency's ISO code (e.g. USD)
     pub code: Option<&'static str>,
+    /// The full name of the currency (e.g. United States Dollar)
+    pub name: Option<&'static str>,
+    /// The symbol placed at the sign position of negative amounts, instead of -
+    pub negative_symbol: Option<&'static str>,
+    /// Whether to omit the minor units when they are all zero (e.g. $100 instead of $100.00)
+    pub trim_trailing_zeros: bool,
+    /// Whether to wrap negative amounts in parentheses instead of showing a sign (e.g. ($1,000.00))
+    pub accounting: bool,
+    /// The fewest minor unit digits to show, padding with zeros (e.g. 12.30 for 2)
+    pub min_fraction_digits: Option<u32>,
+    /// The most minor unit digits to show after Round::HalfEven is applied, omitting trailing zeros
+    pub max_fraction_digits: Option<u32>,
 }
 
 impl Default for Params {
@@ -119,6 +288,12 @@ impl Default for Params {
             rounding: None,
             symbol: None,
             code: None,
+            name: None,
+            negative_symbol: None,
+            trim_trailing_zeros: false,
+            accounting: false,
+            min_fraction_digits: None,
+            max_fraction_digits: None,
         }
     }
 }
@@ -162,7 +337,7 @@ mod tests {
             ],
             ..Default::default()
         };
-        assert_eq!("- $1,000 USD", Formatter::money(&money, params));
+        assert_eq!("- $1,000.00 USD", Formatter::money(&money, params));
 
         let params = Params {
             symbol: Some("$"),
@@ -177,14 +352,14 @@ mod tests {
             ],
             ..Default::default()
         };
-        assert_eq!("USD 1,000$ -", Formatter::money(&money, params));
+        assert_eq!("USD 1,000.00$ -", Formatter::money(&money, params));
 
         // Test that you can omit some, and it works fine.
         let params = Params {
             positions: vec![Position::Amount],
             ..Default::default()
         };
-        assert_eq!("1,000", Formatter::money(&money, params));
+        assert_eq!("1,000.00", Formatter::money(&money, params));
 
         let params = Params {
             symbol: Some("$"),
@@ -198,9 +373,116 @@ mod tests {
             positions: vec![Position::Amount, Position::Symbol],
             ..Default::default()
         };
+        assert_eq!("1,000.00", Formatter::money(&money, params));
+    }
+
+    #[test]
+    fn format_decimal_without_currency() {
+        let amount = Decimal::new(123_456_789, 2);
+        let en_us = LocalFormat::from_locale(crate::Locale::EnUs);
+        let en_eu = LocalFormat::from_locale(crate::Locale::EnEu);
+        let en_in = LocalFormat::from_locale(crate::Locale::EnIn);
+
+        assert_eq!(format_decimal(amount, &en_us, 2), "1,234,567.89");
+        assert_eq!(format_decimal(amount, &en_eu, 2), "1.234.567,89");
+        assert_eq!(format_decimal(amount, &en_in, 2), "12,34,567.89");
+
+        // Rounds and pads to the requested decimals
+        assert_eq!(format_decimal(amount, &en_us, 0), "1,234,568");
+        assert_eq!(format_decimal(amount, &en_us, 4), "1,234,567.8900");
+        assert_eq!(format_decimal(-amount, &en_us, 1), "-1,234,567.9");
+
+        let trailing = LocalFormat::from_locale(crate::Locale::EnEuTrailingSign);
+        assert_eq!(format_decimal(-amount, &trailing, 2), "1.234.567,89-");
+
+        // Negative amounts that round to zero have no sign
+        assert_eq!(format_decimal(Decimal::new(-1, 3), &en_us, 2), "0.00");
+    }
+
+    #[test]
+    fn format_trim_trailing_zeros() {
+        let params = Params {
+            trim_trailing_zeros: true,
+            ..Default::default()
+        };
+
+        let money = Money::from_minor(100_000, test::USD);
+        assert_eq!("1,000", Formatter::money(&money, params.clone()));
+
+        // Non-zero minor units are kept in full
+        let money = Money::from_minor(100_010, test::USD);
+        assert_eq!("1,000.10", Formatter::money(&money, params.clone()));
+
+        let money = Money::from_str("1000.000", test::USD).unwrap();
+        assert_eq!("1,000", Formatter::money(&money, params.clone()));
+
+        let money = Money::from_str("1000.100", test::USD).unwrap();
+        assert_eq!("1,000.100", Formatter::money(&money, params.clone()));
+
+        // Rounding is applied before trimming
+        let params = Params {
+            rounding: Some(2),
+            ..params
+        };
+        let money = Money::from_str("1000.001", test::USD).unwrap();
         assert_eq!("1,000", Formatter::money(&money, params));
     }
 
+    #[test]
+    fn format_spaces() {
+        let money = Money::from_major(1000, test::USD);
+        let params = Params {
+            symbol: Some("$"),
+            code: Some("USD"),
+            positions: vec![
+                Position::Symbol,
+                Position::Spaces(2),
+                Position::Amount,
+                Position::Spaces(3),
+                Position::Code,
+            ],
+            ..Default::default()
+        };
+        assert_eq!("$  1,000.00   USD", Formatter::money(&money, params));
+
+        // Zero spaces insert nothing, and one matches Position::Space
+        let params = Params {
+            symbol: Some("$"),
+            positions: vec![
+                Position::Symbol,
+                Position::Spaces(0),
+                Position::Amount,
+                Position::Spaces(1),
+                Position::Symbol,
+            ],
+            ..Default::default()
+        };
+        assert_eq!("$1,000.00 $", Formatter::money(&money, params));
+    }
+
+    #[test]
+    fn format_name() {
+        let money = Money::from_minor(-100_000, test::USD);
+        let params = Params {
+            name: Some("US Dollars"),
+            positions: vec![
+                Position::Sign,
+                Position::Amount,
+                Position::Space,
+                Position::Name,
+            ],
+            ..Default::default()
+        };
+        assert_eq!("-1,000.00 US Dollars", Formatter::money(&money, params));
+
+        // A missing name inserts nothing
+        let params = Params {
+            positions: vec![Position::Amount, Position::Name],
+            ..Default::default()
+        };
+        assert_eq!("1,000.00", Formatter::money(&money, params));
+    }
+
     #[test]
     fn format_digit_separators_with_custom_separators() {
         let params = Params {
@@ -210,15 +492,15 @@ mod tests {
 
         // For 1_000_000
         let money = Money::from_major(1_000_000, test::USD);
-        assert_eq!("1/000/000", Formatter::money(&money, params.clone()));
+        assert_eq!("1/000/000.00", Formatter::money(&money, params.clone()));
 
         // For 1_000
         let money = Money::from_major(1_000, test::USD);
-        assert_eq!("1/000", Formatter::money(&money, params.clone()));
+        assert_eq!("1/000.00", Formatter::money(&money, params.clone()));
 
         // For 0 Chars
         let money = Money::from_major(0, test::USD);
-        assert_eq!("0", Formatter::money(&money, params));
+        assert_eq!("0.00", Formatter::money(&money, params));
     }
 
     #[test]
@@ -229,13 +511,13 @@ mod tests {
         };
 
         let money = Money::from_major(10_000_000, test::USD);
-        assert_eq!("1,00,00,000", Formatter::money(&money, params.clone()));
+        assert_eq!("1,00,00,000.00", Formatter::money(&money, params.clone()));
 
         let money = Money::from_major(100_000, test::USD);
-        assert_eq!("1,00,000", Formatter::money(&money, params.clone()));
+        assert_eq!("1,00,000.00", Formatter::money(&money, params.clone()));
 
         let money = Money::from_major(1_000, test::USD);
-        assert_eq!("1,000", Formatter::money(&money, params));
+        assert_eq!("1,000.00", Formatter::money(&money, params));
 
         // With a zero sequence
         let params = Params {
@@ -244,10 +526,104 @@ mod tests {
         };
 
         let money = Money::from_major(100, test::USD);
-        assert_eq!("1,00,", Formatter::money(&money, params.clone()));
+        assert_eq!("1,00,.00", Formatter::money(&money, params.clone()));
 
         let money = Money::from_major(0, test::USD);
-        assert_eq!("0,", Formatter::money(&money, params));
+        assert_eq!("0,.00", Formatter::money(&money, params));
+    }
+
+    #[test]
+    fn format_negative_symbol() {
+        let money = Money::from_major(-1000, test::USD);
+
+        let params = Params {
+            symbol: Some("$"),
+            negative_symbol: Some("(neg)"),
+            positions: vec![
+                Position::Sign,
+                Position::Space,
+                Position::Symbol,
+                Position::Amount,
+            ],
+            ..Default::default()
+        };
+        assert_eq!("(neg) $1,000.00", Formatter::money(&money, params.clone()));
+
+        // Positive amounts do not render the negative symbol
+        let money = Money::from_major(1000, test::USD);
+        assert_eq!(" $1,000.00", Formatter::money(&money, params));
+
+        let params = Params {
+            negative_symbol: Some("▼"),
+            positions: vec![Position::Amount, Position::Sign],
+            ..Default::default()
+        };
+        let money = Money::from_major(-1000, test::USD);
+        assert_eq!("1,000.00▼", Formatter::money(&money, params));
+    }
+
+    #[test]
+    fn format_fraction_digits() {
+        let params = Params {
+            symbol: Some("$"),
+            min_fraction_digits: Some(2),
+            max_fraction_digits: Some(4),
+            ..Default::default()
+        };
+
+        let money = Money::from_str("12.3", test::USD).unwrap();
+        assert_eq!("$12.30", Formatter::money(&money, params.clone()));
+
+        let money = Money::from_str("12.3456", test::USD).unwrap();
+        assert_eq!("$12.3456", Formatter::money(&money, params.clone()));
+
+        let money = Money::from_str("12.34567", test::USD).unwrap();
+        assert_eq!("$12.3457", Formatter::money(&money, params.clone()));
+
+        // Zeros beyond the minimum are omitted
+        let money = Money::from_str("12.3000", test::USD).unwrap();
+        assert_eq!("$12.30", Formatter::money(&money, params.clone()));
+
+        let money = Money::from_major(12, test::USD);
+        assert_eq!("$12.00", Formatter::money(&money, params));
+
+        // Either bound can be used alone
+        let params = Params {
+            min_fraction_digits: Some(3),
+            ..Default::default()
+        };
+        let money = Money::from_str("12.3", test::USD).unwrap();
+        assert_eq!("12.300", Formatter::money(&money, params));
+
+        let params = Params {
+            max_fraction_digits: Some(1),
+            ..Default::default()
+        };
+        let money = Money::from_str("12.34", test::USD).unwrap();
+        assert_eq!("12.3", Formatter::money(&money, params));
+    }
+
+    #[test]
+    fn format_accounting() {
+        let params = Params {
+            symbol: Some("$"),
+            accounting: true,
+            ..Default::default()
+        };
+
+        let money = Money::from_minor(-100_000, test::USD);
+        assert_eq!("($1,000.00)", Formatter::money(&money, params.clone()));
+
+        let money = Money::from_minor(100_000, test::USD);
+        assert_eq!("$1,000.00", Formatter::money(&money, params.clone()));
+
+        // The parentheses replace any negative symbol
+        let params = Params {
+            negative_symbol: Some("(neg)"),
+            ..params
+        };
+        let money = Money::from_minor(-100_000, test::USD);
+        assert_eq!("($1,000.00)", Formatter::money(&money, params));
     }
 
     // What if pattern includes a zero or negative number?
@@ -279,4 +655,45 @@ mod tests {
             Formatter::money(&money, params)
         );
     }
+
+    #[test]
+    fn format_never_panics_on_random_amounts() {
+        use crate::{LocalFormat, Locale::*};
+        use rust_decimal::Decimal;
+
+        let mut formats: Vec<LocalFormat> = [EnUs, EnIn, EnEu, EnBy, DeCh, EnEuTrailingSign]
+            .iter()
+            .map(|locale| LocalFormat::from_locale(*locale))
+            .collect();
+        formats.push(LocalFormat {
+            name: "multibyte",
+            digit_separator: '\u{202f}',
+            digit_separator_pattern: "0, 1, x, 18446744073709551615, 2",
+            exponent_separator: '·',
+            trailing_sign: false,
+        });
+
+        // Xorshift, so the amounts are varied but the test is deterministic.
+        let mut seed: u64 = 0x2545_f491_4f6c_dd1d;
+        let mut next = || {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            seed
+        };
+
+        for _ in 0..2_000 {
+            let mantissa = ((next() as i128) << 32 | next() as i128 >> 32) % (1 << 96);
+            let mantissa = if next() % 2 == 0 { mantissa } else { -mantissa };
+            let scale = (next() % 29) as u32;
+            let money =
+                Money::from_decimal(Decimal::from_i128_with_scale(mantissa, scale), test::USD);
+
+            for format in formats.iter() {
+                let formatted = money.format_with(format);
+                assert!(!formatted.is_empty());
+            }
+            let _ = format!("{}", money);
+        }
+    }
 }
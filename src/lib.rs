@@ -1,15 +1,28 @@
 #![doc = include_str!("../README.md")]
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
+
+extern crate alloc;
 
 mod currency;
 mod error;
+#[cfg(feature = "std")]
 mod exchange;
 mod format;
 mod locale;
 mod money;
+mod typed_money;
 
 pub use currency::*;
-pub use error::MoneyError;
+pub use error::{AmountError, MoneyError};
+#[cfg(feature = "std")]
 pub use exchange::*;
 pub use format::*;
 pub use locale::*;
 pub use money::*;
+pub use typed_money::*;
+
+/// Items used by `define_currency_set!`, which must also resolve in `no_std` crates.
+#[doc(hidden)]
+pub mod __private {
+    pub use alloc::string::{String, ToString};
+}
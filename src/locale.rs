@@ -1,4 +1,5 @@
-use std::str::FromStr;
+use alloc::vec::Vec;
+use core::str::FromStr;
 
 /// Enumerates regions which have unique formatting standards for Currencies.
 ///
@@ -9,6 +10,8 @@ pub enum Locale {
     EnIn,
     EnEu,
     EnBy,
+    DeCh,
+    EnEuTrailingSign,
 }
 
 /// Stores currency formatting metadata for a specific region (e.g. EN-US).
@@ -18,15 +21,20 @@ pub struct LocalFormat {
     pub digit_separator: char,
     pub digit_separator_pattern: &'static str,
     pub exponent_separator: char,
+    /// Whether the negative sign follows the amount (e.g. 1.000,00-) instead of preceding it.
+    pub trailing_sign: bool,
 }
 
 impl LocalFormat {
     /// Returns a vector indicating where digit separators should be applied on a Money amount.
     ///
     /// For example, `3, 3, 3` indicates that the digit separator should be applied after the 3rd, 6th and 9th digits.
+    /// Entries that are not numbers are ignored.
     pub fn digit_separator_pattern(&self) -> Vec<usize> {
-        let v: Vec<&str> = self.digit_separator_pattern.split(", ").collect();
-        v.iter().map(|x| usize::from_str(x).unwrap()).collect()
+        self.digit_separator_pattern
+            .split(',')
+            .filter_map(|x| usize::from_str(x.trim()).ok())
+            .collect()
     }
 
     /// Returns the associated LocalFormat given a Locale.
@@ -39,24 +47,42 @@ impl LocalFormat {
                 digit_separator: ',',
                 digit_separator_pattern: "3, 3, 3",
                 exponent_separator: '.',
+                trailing_sign: false,
             },
             EnIn => LocalFormat {
                 name: "en-in",
                 digit_separator: ',',
                 digit_separator_pattern: "3, 2, 2",
                 exponent_separator: '.',
+                trailing_sign: false,
             },
             EnEu => LocalFormat {
                 name: "en-eu",
                 digit_separator: '.',
                 digit_separator_pattern: "3, 3, 3",
                 exponent_separator: ',',
+                trailing_sign: false,
             },
             EnBy => LocalFormat {
                 name: "en-by",
                 digit_separator: ' ',
                 digit_separator_pattern: "3, 3, 3",
                 exponent_separator: ',',
+                trailing_sign: false,
+            },
+            DeCh => LocalFormat {
+                name: "de-ch",
+                digit_separator: '\'',
+                digit_separator_pattern: "3, 3, 3",
+                exponent_separator: '.',
+                trailing_sign: false,
+            },
+            EnEuTrailingSign => LocalFormat {
+                name: "en-eu-trailing-sign",
+                digit_separator: '.',
+                digit_separator_pattern: "3, 3, 3",
+                exponent_separator: ',',
+                trailing_sign: true,
             },
         }
     }
@@ -1,14 +1,23 @@
 use crate::currency::FormattableCurrency;
-use crate::format::{Formatter, Params, Position};
+use crate::format::{Formatter, MoneyFormatter, Params, Position};
 use crate::locale::LocalFormat;
-use crate::MoneyError;
-
-use std::cmp::Ordering;
-use std::fmt;
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
-use std::str::FromStr;
-
+#[cfg(feature = "std")]
+use crate::Exchange;
+use crate::{AmountError, MoneyError};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
+
+use core::cmp::Ordering;
+use core::fmt::{self, Write};
+use core::hash::{Hash, Hasher};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use core::str::FromStr;
+
+use rust_decimal::prelude::ToPrimitive;
 use rust_decimal::Decimal;
+#[cfg(feature = "serde")]
+use serde::{de, ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
 
 /// Represents an amount of a given currency.
 ///
@@ -21,6 +30,18 @@ pub struct Money<'a, T: FormattableCurrency> {
     currency: &'a T,
 }
 
+/// Hashes the amount and currency code, consistently with `PartialEq`.
+///
+/// Decimal equality is numeric, so 1.0 and 1.00 USD are the same key. Amounts that only differ
+/// below the currency's precision (e.g. 10.001 and 10.00 USD) are different keys, so normalize
+/// them with `round_to_currency` first if they should be deduplicated.
+impl<'a, T: FormattableCurrency> Hash for Money<'a, T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.amount.hash(state);
+        self.currency.code().hash(state);
+    }
+}
+
 impl<'a, T: FormattableCurrency> Add for Money<'a, T> {
     type Output = Money<'a, T>;
     fn add(self, other: Money<'a, T>) -> Money<'a, T> {
@@ -165,9 +186,19 @@ impl<'a, T: FormattableCurrency> Ord for Money<'a, T> {
 impl<'a, T: FormattableCurrency> Money<'a, T> {
     /// Creates a Money object given an amount string and a currency str.
     ///
-    /// Supports fuzzy amount strings like "100", "100.00" and "-100.00"
+    /// Supports fuzzy amount strings like "100", "100.00" and "-100.00". Digits may be grouped
+    /// with the locale's separator, apostrophes (1'000) or spaces (1 000).
     pub fn from_str(amount: &str, currency: &'a T) -> Result<Money<'a, T>, MoneyError> {
         let format = LocalFormat::from_locale(currency.locale());
+
+        // Apostrophes and spaces are accepted as digit separators, regardless of locale.
+        let amount: String = amount
+            .chars()
+            .map(|c| match c {
+                '\'' | ' ' | '\u{a0}' | '\u{202f}' => format.digit_separator,
+                c => c,
+            })
+            .collect();
         let amount_parts: Vec<&str> = amount.split(format.exponent_separator).collect();
 
         let mut split_decimal: Vec<&str> = amount_parts[0].split(format.digit_separator).collect();
@@ -180,7 +211,9 @@ impl<'a, T: FormattableCurrency> Money<'a, T> {
             }
             let current = split_decimal.pop().unwrap();
             if current.len() != num {
-                return Err(MoneyError::InvalidAmount);
+                return Err(MoneyError::InvalidAmount(Some(
+                    AmountError::InvalidGrouping,
+                )));
             }
         }
 
@@ -190,16 +223,52 @@ impl<'a, T: FormattableCurrency> Money<'a, T> {
                 parsed_decimal += "0";
             }
         } else if amount_parts.len() == 2 {
-            i32::from_str(amount_parts[1])?;
-            parsed_decimal = parsed_decimal + "." + amount_parts[1];
+            // Validate digits directly, since the exponent may be longer than any integer type.
+            let exponent = amount_parts[1];
+            if exponent.is_empty() {
+                return Err(MoneyError::InvalidAmount(Some(
+                    AmountError::MissingFraction,
+                )));
+            }
+            if !exponent.chars().all(|c| c.is_ascii_digit()) {
+                return Err(MoneyError::InvalidAmount(Some(AmountError::NonNumeric)));
+            }
+            parsed_decimal = parsed_decimal + "." + exponent;
         } else {
-            return Err(MoneyError::InvalidAmount);
+            return Err(MoneyError::InvalidAmount(Some(
+                AmountError::MultipleExponentSeparators,
+            )));
         }
 
-        let decimal = Decimal::from_str(&parsed_decimal).unwrap();
+        let decimal = Decimal::from_str(&parsed_decimal).map_err(|_| {
+            // Well-formed digits that still fail to parse cannot fit in a Decimal.
+            let digits = parsed_decimal
+                .trim_start_matches(['-', '+'])
+                .replace('.', "");
+            if !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit()) {
+                MoneyError::InvalidAmount(Some(AmountError::TooManyDigits))
+            } else {
+                MoneyError::InvalidAmount(Some(AmountError::NonNumeric))
+            }
+        })?;
         Ok(Money::from_decimal(decimal, currency))
     }
 
+    /// Creates a Money object given an amount string and a currency str, enforcing precision.
+    ///
+    /// Behaves like `from_str`, but returns `MoneyError::ExcessPrecision` if the amount has more
+    /// fractional digits than the currency's exponent (e.g. "29.111" in USD or "100.00" in JPY).
+    pub fn from_str_strict(amount: &str, currency: &'a T) -> Result<Money<'a, T>, MoneyError> {
+        let money = Money::from_str(amount, currency)?;
+        if money.amount.scale() > currency.exponent() {
+            return Err(MoneyError::ExcessPrecision {
+                provided: money.amount.scale(),
+                allowed: currency.exponent(),
+            });
+        }
+        Ok(money)
+    }
+
     /// Creates a Money object given an integer and a currency reference.
     ///
     /// The integer represents minor units of the currency (e.g. 1000 -> 10.00 in USD )
@@ -208,19 +277,79 @@ impl<'a, T: FormattableCurrency> Money<'a, T> {
         Money { amount, currency }
     }
 
+    /// Creates a Money object given an i128 amount in minor units, for amounts beyond an i64
+    /// (e.g. 100 ETH is 10^20 wei).
+    ///
+    /// Returns `MoneyError::Overflow` if the amount has more than 96 bits, the most a Decimal holds.
+    pub fn from_minor_i128(amount: i128, currency: &'a T) -> Result<Money<'a, T>, MoneyError> {
+        let amount = Decimal::try_from_i128_with_scale(amount, currency.exponent())
+            .map_err(|_| MoneyError::Overflow)?;
+        Ok(Money { amount, currency })
+    }
+
     /// Creates a Money object given an integer and a currency reference.
     ///
-    /// The integer represents major units of the currency (e.g. 1000 -> 1,000 in USD )
+    /// The integer represents major units of the currency (e.g. 1000 -> 1,000.00 in USD ), and the
+    /// amount has the same scale as `from_minor`, so both display identically.
     pub fn from_major(amount: i64, currency: &'a T) -> Money<'a, T> {
-        let amount = Decimal::new(amount, 0);
+        let mut amount = Decimal::new(amount, 0);
+        amount.rescale(currency.exponent());
         Money { amount, currency }
     }
 
+    /// Creates a Money object given an integer of major units, at the currency's exponent scale.
+    ///
+    /// Like `from_major`, but returns `MoneyError::Overflow` if the amount cannot be represented
+    /// at that scale instead of falling back to a smaller one.
+    pub fn from_major_checked(amount: i64, currency: &'a T) -> Result<Money<'a, T>, MoneyError> {
+        let exponent = currency.exponent();
+        let amount = 10i128
+            .checked_pow(exponent)
+            .and_then(|scale| i128::from(amount).checked_mul(scale))
+            .and_then(|mantissa| Decimal::try_from_i128_with_scale(mantissa, exponent).ok())
+            .ok_or(MoneyError::Overflow)?;
+        Ok(Money { amount, currency })
+    }
+
     /// Creates a Money object given a decimal amount and a currency reference.
+    ///
+    /// The amount is not checked against the currency's precision, so 10.005 USD is accepted.
+    /// Use `try_from_decimal` to reject such amounts.
     pub fn from_decimal(amount: Decimal, currency: &'a T) -> Money<'a, T> {
         Money { amount, currency }
     }
 
+    /// Creates a Money object given a decimal amount and a currency reference, checking bounds
+    /// and precision.
+    ///
+    /// Returns `MoneyError::Overflow` if the amount falls outside of `min_representable`
+    /// and `max_representable` for the currency, and `MoneyError::PrecisionLoss` if it is not a
+    /// whole number of minor units (e.g. 10.005 USD).
+    pub fn try_from_decimal(amount: Decimal, currency: &'a T) -> Result<Money<'a, T>, MoneyError> {
+        if amount > *Money::max_representable(currency).amount()
+            || amount < *Money::min_representable(currency).amount()
+        {
+            return Err(MoneyError::Overflow);
+        }
+        let money = Money::from_decimal(amount, currency);
+        if !money.lands_on_minor_unit() {
+            return Err(MoneyError::PrecisionLoss);
+        }
+        Ok(money)
+    }
+
+    /// Returns the largest Money that a Decimal can hold at the currency's exponent.
+    pub fn max_representable(currency: &'a T) -> Money<'a, T> {
+        let amount = Decimal::from_i128_with_scale(Decimal::MAX.mantissa(), currency.exponent());
+        Money { amount, currency }
+    }
+
+    /// Returns the smallest Money that a Decimal can hold at the currency's exponent.
+    pub fn min_representable(currency: &'a T) -> Money<'a, T> {
+        let amount = Decimal::from_i128_with_scale(Decimal::MIN.mantissa(), currency.exponent());
+        Money { amount, currency }
+    }
+
     /// Returns a reference to the Decimal amount.
     pub fn amount(&self) -> &Decimal {
         &self.amount
@@ -246,6 +375,13 @@ impl<'a, T: FormattableCurrency> Money<'a, T> {
         self.amount.is_sign_negative() && self.amount != Decimal::ZERO
     }
 
+    /// Returns the absolute value of this Money.
+    ///
+    /// A Decimal amount cannot overflow when negated, so this always succeeds.
+    pub fn checked_abs(&self) -> Result<Money<'a, T>, MoneyError> {
+        Ok(Money::from_decimal(self.amount.abs(), self.currency))
+    }
+
     /// Divides money equally into n shares.
     ///
     /// If the division cannot be applied perfectly, it allocates the remainder
@@ -258,7 +394,7 @@ impl<'a, T: FormattableCurrency> Money<'a, T> {
     /// Divides money into n shares according to a particular ratio.
     ///
     /// If the division cannot be applied perfectly, it allocates the remainder
-    /// to some of the shares.
+    /// to some of the shares. Returns `MoneyError::Overflow` if a share cannot be computed.
     pub fn allocate(&self, ratios: Vec<i32>) -> Result<Vec<Money<'a, T>>, MoneyError> {
         if ratios.is_empty() {
             return Err(MoneyError::InvalidRatio);
@@ -270,7 +406,10 @@ impl<'a, T: FormattableCurrency> Money<'a, T> {
             .collect();
 
         let mut remainder = self.amount;
-        let ratio_total: Decimal = ratios.iter().fold(Decimal::ZERO, |acc, x| acc + x);
+        let ratio_total = ratios
+            .iter()
+            .try_fold(Decimal::ZERO, |acc, x| acc.checked_add(*x))
+            .ok_or(MoneyError::Overflow)?;
 
         let mut allocations: Vec<Money<'a, T>> = Vec::new();
 
@@ -279,7 +418,12 @@ impl<'a, T: FormattableCurrency> Money<'a, T> {
                 return Err(MoneyError::InvalidRatio);
             }
 
-            let share = (self.amount * ratio / ratio_total).floor();
+            let share = self
+                .amount
+                .checked_mul(ratio)
+                .and_then(|x| x.checked_div(ratio_total))
+                .ok_or(MoneyError::Overflow)?
+                .floor();
 
             allocations.push(Money::from_decimal(share, self.currency));
             remainder -= share;
@@ -302,508 +446,2712 @@ impl<'a, T: FormattableCurrency> Money<'a, T> {
         Ok(allocations)
     }
 
+    /// The largest number of shares returned by `split_by`.
+    pub const MAX_SPLIT_SHARES: usize = 10_000;
+
+    /// Splits money into as many whole shares of the given size as fit, and the leftover
+    /// (e.g. $350 split by $100 -> three $100 shares and $50).
+    ///
+    /// Errors if the share is in a different currency or is zero, and with `MoneyError::Overflow`
+    /// if more than `MAX_SPLIT_SHARES` shares fit. If no share fits, for example because the
+    /// amounts have different signs, all of the money is left over.
+    pub fn split_by(
+        &self,
+        share: &Money<'a, T>,
+    ) -> Result<(Vec<Money<'a, T>>, Money<'a, T>), MoneyError> {
+        if share.currency != self.currency {
+            return Err(MoneyError::InvalidCurrency);
+        }
+        if share.is_zero() {
+            return Err(MoneyError::DivisionByZero);
+        }
+
+        let count = self
+            .amount
+            .checked_div(share.amount)
+            .ok_or(MoneyError::Overflow)?
+            .trunc();
+        if count <= Decimal::ZERO {
+            return Ok((Vec::new(), *self));
+        }
+
+        let count = count
+            .to_usize()
+            .filter(|&count| count <= Self::MAX_SPLIT_SHARES)
+            .ok_or(MoneyError::Overflow)?;
+        let leftover = self.amount - share.amount * Decimal::from(count);
+        Ok((
+            vec![*share; count],
+            Money::from_decimal(leftover, self.currency),
+        ))
+    }
+
     /// Returns a `Money` rounded to the specified number of minor units using the rounding strategy.
     pub fn round(&self, digits: u32, strategy: Round) -> Money<'a, T> {
         let mut money = *self;
 
-        money.amount = match strategy {
-            Round::HalfDown => money
-                .amount
-                .round_dp_with_strategy(digits, rust_decimal::RoundingStrategy::MidpointTowardZero),
-            Round::HalfUp => money.amount.round_dp_with_strategy(
-                digits,
-                rust_decimal::RoundingStrategy::MidpointAwayFromZero,
-            ),
-            Round::HalfEven => money.amount.round_dp_with_strategy(
-                digits,
-                rust_decimal::RoundingStrategy::MidpointNearestEven,
-            ),
+        let strategy = match strategy {
+            Round::HalfDown => rust_decimal::RoundingStrategy::MidpointTowardZero,
+            Round::HalfUp => rust_decimal::RoundingStrategy::MidpointAwayFromZero,
+            Round::HalfEven => rust_decimal::RoundingStrategy::MidpointNearestEven,
+            Round::Ceiling => rust_decimal::RoundingStrategy::ToPositiveInfinity,
+            Round::Floor => rust_decimal::RoundingStrategy::ToNegativeInfinity,
+            Round::TowardZero => rust_decimal::RoundingStrategy::ToZero,
+            Round::AwayFromZero => rust_decimal::RoundingStrategy::AwayFromZero,
         };
+        money.amount = money.amount.round_dp_with_strategy(digits, strategy);
 
         money
     }
-}
 
-/// Strategies that can be used to round Money.
-///
-/// For more details, see [rust_decimal::RoundingStrategy]
-pub enum Round {
-    HalfUp,
-    HalfDown,
-    HalfEven,
-}
+    /// Returns this Money with a canonical scale, so equal amounts have identical representations.
+    ///
+    /// Trailing zeros are removed down to the currency's exponent, and added up to it when missing,
+    /// so 10, 10.0 and 10.000 USD all become 10.00 USD. Precision beyond the exponent is kept.
+    pub fn normalized(&self) -> Money<'a, T> {
+        let mut amount = self.amount.normalize();
+        if amount.scale() < self.currency.exponent() {
+            amount.rescale(self.currency.exponent());
+        }
+        Money::from_decimal(amount, self.currency)
+    }
 
-impl<'a, T: FormattableCurrency + FormattableCurrency> fmt::Display for Money<'a, T> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let currency = self.currency;
-        let format = LocalFormat::from_locale(currency.locale());
+    /// Returns a `Money` rounded to the currency's exponent using the rounding strategy.
+    pub fn round_to_currency(&self, strategy: Round) -> Money<'a, T> {
+        self.round(self.currency.exponent(), strategy)
+    }
 
-        let mut format_params = Params {
-            digit_separator: format.digit_separator,
-            exponent_separator: format.exponent_separator,
-            separator_pattern: format.digit_separator_pattern(),
-            rounding: Some(currency.exponent()),
-            symbol: Some(currency.symbol()),
-            code: Some(currency.code()),
-            ..Default::default()
-        };
+    /// Returns true if both Money objects are in the same currency, so they can be combined
+    /// without panicking.
+    ///
+    /// Currencies are compared by value, so separate copies of the same currency match.
+    pub fn is_same_currency(&self, other: &Money<'a, T>) -> bool {
+        self.currency == other.currency
+    }
 
-        if currency.symbol_first() {
-            format_params.positions = vec![Position::Sign, Position::Symbol, Position::Amount];
-            write!(f, "{}", Formatter::money(self, format_params))
-        } else {
-            format_params.positions = vec![Position::Sign, Position::Amount, Position::Symbol];
-            write!(f, "{}", Formatter::money(self, format_params))
+    /// Returns true if both Money objects are equal after rounding half-even to the currency's exponent.
+    ///
+    /// Unlike `==`, sub-minor-unit differences are ignored (e.g. 10.001 USD equals 10.00 USD).
+    /// Money in different currencies is never equal.
+    pub fn eq_rounded(&self, other: &Money<'a, T>) -> bool {
+        self.currency == other.currency
+            && self.round_to_currency(Round::HalfEven) == other.round_to_currency(Round::HalfEven)
+    }
+
+    /// Returns the smaller of two Money objects, or `self` if they are equal.
+    ///
+    /// Unlike `Ord::min`, which panics, errors if the currencies differ.
+    pub fn min(self, other: Money<'a, T>) -> Result<Money<'a, T>, MoneyError> {
+        if self.currency != other.currency {
+            return Err(MoneyError::InvalidCurrency);
         }
+        Ok(if other.amount < self.amount {
+            other
+        } else {
+            self
+        })
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::define_currency_set;
+    /// Returns the larger of two Money objects, or `self` if they are equal.
+    ///
+    /// Unlike `Ord::max`, which panics, errors if the currencies differ.
+    pub fn max(self, other: Money<'a, T>) -> Result<Money<'a, T>, MoneyError> {
+        if self.currency != other.currency {
+            return Err(MoneyError::InvalidCurrency);
+        }
+        Ok(if other.amount > self.amount {
+            other
+        } else {
+            self
+        })
+    }
 
-    define_currency_set!(
-        test {
-            USD: {
-                code: "USD",
-                exponent: 2,
-                locale: EnUs,
-                minor_units: 100,
-                name: "USD",
-                symbol: "$",
-                symbol_first: true,
-            },
-            GBP : {
-                code: "GBP",
-                exponent: 2,
-                locale: EnUs,
-                minor_units: 1,
-                name: "British Pound",
-                symbol: "£",
-                symbol_first: true,
-            },
-            EUR : {
-                code: "EUR",
-                exponent: 2,
-                locale: EnEu,
-                minor_units: 1,
-                name: "Euro",
-                symbol: "€",
-                symbol_first: true,
-            },
-            INR : {
-                code: "INR",
-                exponent: 2,
-                locale: EnIn,
-                minor_units: 50,
-                name: "Indian Rupee",
-                symbol: "₹",
-                symbol_first: true,
-            },
-            BHD : {
-                code: "BHD",
-                exponent: 3,
-                locale: EnUs,
-                minor_units: 5,
-                name: "Bahraini Dinar",
-                symbol: "ب.د",
-                symbol_first: true,
-            },
-            AED : {
-                code: "AED",
-                exponent: 2,
-                locale: EnUs,
-                minor_units: 25,
-                name: "United Arab Emirates Dirham",
-                symbol: "د.إ",
-                symbol_first: false,
-            }
+    /// Returns `low` if this Money is below it, `high` if it is above it, and itself otherwise.
+    ///
+    /// Unlike `Ord::clamp`, which panics, errors if the currencies differ, and with
+    /// `MoneyError::InvalidRange` if low is greater than high.
+    pub fn clamp(
+        self,
+        low: &Money<'a, T>,
+        high: &Money<'a, T>,
+    ) -> Result<Money<'a, T>, MoneyError> {
+        if low.currency != self.currency || high.currency != self.currency {
+            return Err(MoneyError::InvalidCurrency);
+        }
+        if low.amount > high.amount {
+            return Err(MoneyError::InvalidRange);
         }
-    );
 
-    #[test]
-    fn money_major_minor() {
-        let _usd = test::find("USD"); // Prevents unused code warnings from the defined module.
-        let major_usd = Money::from_major(10, test::USD);
-        let minor_usd = Money::from_minor(1000, test::USD);
-        assert_eq!(major_usd, minor_usd);
+        Ok(if self.amount < low.amount {
+            *low
+        } else if self.amount > high.amount {
+            *high
+        } else {
+            self
+        })
     }
 
-    #[test]
-    fn money_from_string_parses_correctly() {
-        let expected_money = Money::from_minor(2999, test::GBP);
-        let money = Money::from_str("29.99", test::GBP).unwrap();
-        assert_eq!(money, expected_money);
+    /// Returns the amount rounded to the currency's exponent, without symbols or digit separators.
+    ///
+    /// Always uses `.` as the exponent separator regardless of locale (e.g. 1234.50 for USD and
+    /// 500 for JPY), which suits logs and machine-readable exports.
+    pub fn to_plain_string(&self) -> String {
+        let exponent = self.currency.exponent() as usize;
+        format!(
+            "{:.*}",
+            exponent,
+            self.round_to_currency(Round::HalfEven).amount
+        )
     }
 
-    #[test]
-    fn money_from_string_parses_correctly_for_64_bit_numbers() {
-        let expected_money = Money::from_major(i64::MAX, test::GBP);
-        let money = Money::from_str(&i64::MAX.to_string(), test::GBP).unwrap();
-        assert_eq!(money, expected_money);
+    /// Returns the formatted Money as a CSV field, quoting it if it contains separators.
+    pub fn to_csv_field(&self) -> String {
+        let formatted = self.to_string();
+        if formatted.contains([',', '"', '\n', '\r']) {
+            format!("\"{}\"", formatted.replace('"', "\"\""))
+        } else {
+            formatted
+        }
     }
 
-    #[test]
-    fn money_from_string_parses_signs() {
-        let expected_money = Money::from_minor(-300, test::GBP);
-        let money = Money::from_str("-3", test::GBP).unwrap();
-        assert_eq!(money, expected_money);
+    /// Returns the percentage of this Money that each of the parts represents.
+    ///
+    /// Errors if any part is in a different currency, if this Money is zero, or with
+    /// `MoneyError::Overflow` if a percentage is out of bounds.
+    pub fn percentages_of(&self, parts: &[Money<'a, T>]) -> Result<Vec<Decimal>, MoneyError> {
+        if self.is_zero() {
+            return Err(MoneyError::DivisionByZero);
+        }
 
-        let expected_money = Money::from_minor(300, test::GBP);
-        let money = Money::from_str("+3", test::GBP).unwrap();
-        assert_eq!(money, expected_money);
+        parts
+            .iter()
+            .map(|part| {
+                if part.currency != self.currency {
+                    return Err(MoneyError::InvalidCurrency);
+                }
+                part.amount
+                    .checked_mul(Decimal::ONE_HUNDRED)
+                    .and_then(|scaled| scaled.checked_div(self.amount))
+                    .ok_or(MoneyError::Overflow)
+            })
+            .collect()
     }
 
-    #[test]
-    fn money_from_string_ignores_separators() {
-        let expected_money = Money::from_minor(100000000, test::GBP);
-        let money = Money::from_str("1,000,000", test::GBP).unwrap();
-        assert_eq!(money, expected_money);
+    /// Returns the given percentage of this Money (e.g. 15% of 20.00 USD is 3.00 USD).
+    ///
+    /// The amount is multiplied before it is divided and is not rounded, so precision beyond the
+    /// currency's exponent is kept until the caller rounds it. Errors with `MoneyError::Overflow`
+    /// if the result is out of bounds.
+    pub fn percent(&self, pct: Decimal) -> Result<Money<'a, T>, MoneyError> {
+        let amount = self
+            .amount
+            .checked_mul(pct)
+            .and_then(|scaled| scaled.checked_div(Decimal::ONE_HUNDRED))
+            .ok_or(MoneyError::Overflow)?;
+        Ok(Money::from_decimal(amount, self.currency))
     }
 
-    #[test]
-    fn money_from_string_decimal_sanity() {
-        let money = Money::from_str("1,00.00", test::GBP);
-        assert_eq!(money.unwrap_err(), MoneyError::InvalidAmount);
+    /// Returns this Money increased by the given percentage, such as a tax or tip, without rounding.
+    ///
+    /// Errors with `MoneyError::Overflow` if the result is out of bounds.
+    pub fn add_percent(&self, pct: Decimal) -> Result<Money<'a, T>, MoneyError> {
+        let amount = self
+            .amount
+            .checked_add(self.percent(pct)?.amount)
+            .ok_or(MoneyError::Overflow)?;
+        Ok(Money::from_decimal(amount, self.currency))
+    }
 
-        let money = Money::from_str("1.00,00", test::EUR);
-        assert_eq!(money.unwrap_err(), MoneyError::InvalidAmount);
+    /// Returns this Money reduced by the given percentage, such as a discount, without rounding.
+    ///
+    /// Errors with `MoneyError::Overflow` if the result is out of bounds.
+    pub fn apply_discount(&self, pct: Decimal) -> Result<Money<'a, T>, MoneyError> {
+        let amount = self
+            .amount
+            .checked_sub(self.percent(pct)?.amount)
+            .ok_or(MoneyError::Overflow)?;
+        Ok(Money::from_decimal(amount, self.currency))
+    }
 
-        let money = Money::from_str("1.00.000,00", test::EUR);
-        assert_eq!(money.unwrap_err(), MoneyError::InvalidAmount);
+    /// Returns the ratio of this Money to another (e.g. 25.00 USD to 100.00 USD is 0.25).
+    ///
+    /// Errors if the currencies differ, or if the other Money is zero.
+    pub fn ratio(&self, other: &Money<'a, T>) -> Result<Decimal, MoneyError> {
+        if other.currency != self.currency {
+            return Err(MoneyError::InvalidCurrency);
+        }
+        if other.is_zero() {
+            return Err(MoneyError::DivisionByZero);
+        }
+        self.amount
+            .checked_div(other.amount)
+            .ok_or(MoneyError::Overflow)
+    }
 
-        let money = Money::from_str("1.00.000.000,00", test::EUR);
-        assert_eq!(money.unwrap_err(), MoneyError::InvalidAmount);
+    /// Returns the signed amount needed to reach the target (e.g. 60.00 USD to 100.00 USD is
+    /// 40.00 USD, and 120.00 USD to 100.00 USD is -20.00 USD).
+    ///
+    /// Errors if the currencies differ, or with `MoneyError::Overflow` if the difference is out of bounds.
+    pub fn difference_to(&self, target: &Money<'a, T>) -> Result<Money<'a, T>, MoneyError> {
+        if target.currency != self.currency {
+            return Err(MoneyError::InvalidCurrency);
+        }
+        let difference = target
+            .amount
+            .checked_sub(self.amount)
+            .ok_or(MoneyError::Overflow)?;
+        Ok(Money::from_decimal(difference, self.currency))
+    }
 
-        let money = Money::from_str("1,00.00", test::INR);
-        assert_eq!(money.unwrap_err(), MoneyError::InvalidAmount);
+    /// Returns the minor units left over after dividing this Money into n equal parts.
+    ///
+    /// For example, 100.00 USD divided by 3 leaves 0.01 USD. Errors with `MoneyError::DivisionByZero`
+    /// if n is zero, and `MoneyError::Overflow` if the amount in minor units is out of bounds.
+    pub fn rem(&self, n: i64) -> Result<Money<'a, T>, MoneyError> {
+        if n == 0 {
+            return Err(MoneyError::DivisionByZero);
+        }
 
-        let money = Money::from_str("1.000.000.00", test::INR);
-        assert_eq!(money.unwrap_err(), MoneyError::InvalidAmount);
+        let scale = self.minor_unit_scale()?;
+        let remainder = self
+            .amount
+            .checked_mul(scale)
+            .and_then(|minor_units| minor_units.checked_rem(Decimal::from(n)))
+            .and_then(|remainder| remainder.checked_div(scale))
+            .ok_or(MoneyError::Overflow)?;
+        Ok(Money::from_decimal(remainder, self.currency))
     }
 
-    #[test]
-    fn money_from_string_parse_errs() {
-        // If the delimiter precede the separators
-        let money = Money::from_str("1.0000,000", test::GBP);
-        assert_eq!(money.unwrap_err(), MoneyError::InvalidAmount);
+    /// Returns the number of minor units in one major unit of the currency (`10^exponent`).
+    ///
+    /// For example, 100 for USD, 1 for JPY and 1000 for BHD. Errors with `MoneyError::Overflow`
+    /// if the exponent is above 28, since a Decimal cannot hold a larger scale.
+    pub fn minor_unit_scale(&self) -> Result<Decimal, MoneyError> {
+        10i128
+            .checked_pow(self.currency.exponent())
+            .and_then(|scale| Decimal::try_from_i128_with_scale(scale, 0).ok())
+            .ok_or(MoneyError::Overflow)
+    }
 
-        // If there are multiple delimiters
-        let money = Money::from_str("1.0000.000", test::GBP);
-        assert_eq!(money.unwrap_err(), MoneyError::InvalidAmount);
+    /// Returns true if the amount is a whole number of the currency's minor units.
+    pub fn lands_on_minor_unit(&self) -> bool {
+        self.amount.round_dp(self.currency.exponent()) == self.amount
+    }
 
-        // If there is an unrecognized character
-        let money = Money::from_str("1.0000!000", test::GBP);
-        assert_eq!(money.unwrap_err(), MoneyError::InvalidAmount);
+    /// Returns true if the amount is a whole number of the currency's smallest cash denomination.
+    ///
+    /// For example, AUD has no coins smaller than 5 cents, so 1.23 AUD cannot be paid in cash.
+    /// Errors with `MoneyError::Overflow` if the denomination cannot be computed.
+    pub fn lands_on_cash_denomination(&self) -> Result<bool, MoneyError> {
+        let denomination = Decimal::from(self.currency.minor_units())
+            .checked_div(self.minor_unit_scale()?)
+            .ok_or(MoneyError::Overflow)?;
+        let remainder = self
+            .amount
+            .checked_rem(denomination)
+            .ok_or(MoneyError::Overflow)?;
+        Ok(remainder.is_zero())
+    }
 
-        // If there are no characters other than separators
-        let exponent_separator_only = Money::from_str(",", test::GBP);
-        let amount_separator_only = Money::from_str(".", test::GBP);
-        let both_separators = Money::from_str(",,.", test::GBP);
-        assert_eq!(
-            exponent_separator_only.unwrap_err(),
-            MoneyError::InvalidAmount
-        );
-        assert_eq!(
-            amount_separator_only.unwrap_err(),
-            MoneyError::InvalidAmount
-        );
-        assert_eq!(both_separators.unwrap_err(), MoneyError::InvalidAmount);
+    /// Returns a `Money` rounded half-even to the currency's smallest cash denomination.
+    ///
+    /// For example, CHF has no coins smaller than 5 centimes, so 1.23 CHF rounds to 1.25 CHF.
+    pub fn round_to_cash_denomination(&self) -> Money<'a, T> {
+        self.round_to_cash_denomination_with(Round::HalfEven)
     }
 
-    #[test]
-    fn money_format_rounds_exponent() {
-        // // 19.999 rounds to 20 for USD
-        let money = Money::from_str("19.9999", test::USD).unwrap();
-        assert_eq!("$20.00", format!("{}", money));
+    /// Returns a `Money` rounded to the currency's smallest cash denomination using the rounding strategy.
+    ///
+    /// Panics if the amount is too large to round or the currency's exponent is above 28. Use
+    /// `round_to_denomination_checked` to handle these cases as errors.
+    pub fn round_to_cash_denomination_with(&self, strategy: Round) -> Money<'a, T> {
+        match self.round_to_denomination_checked(strategy) {
+            Ok(money) => money,
+            Err(err) => panic!(
+                "amount cannot be rounded to the cash denomination: {:?}",
+                err
+            ),
+        }
+    }
 
-        // // 29.111 rounds to 29.11 for USD
-        let money = Money::from_str("29.111", test::USD).unwrap();
-        assert_eq!("$29.11", format!("{}", money));
+    /// Returns a `Money` rounded to the currency's smallest cash denomination using the rounding
+    /// strategy, or `MoneyError::Overflow` if the amount is too large to round.
+    pub fn round_to_denomination_checked(
+        &self,
+        strategy: Round,
+    ) -> Result<Money<'a, T>, MoneyError> {
+        let denomination = Decimal::from(self.currency.minor_units())
+            .checked_div(self.minor_unit_scale()?)
+            .ok_or(MoneyError::Overflow)?;
+        let units = self
+            .amount
+            .checked_div(denomination)
+            .ok_or(MoneyError::Overflow)?;
+        let units = Money::from_decimal(units, self.currency).round(0, strategy);
+        let amount = units
+            .amount
+            .checked_mul(denomination)
+            .ok_or(MoneyError::Overflow)?;
+        Ok(Money::from_decimal(amount, self.currency))
+    }
 
-        // // 39.1155 rounds to 39.116 for BHD
-        let money = Money::from_str("39.1155", test::BHD).unwrap();
-        assert_eq!("ب.د39.116", format!("{}", money));
+    /// Returns a description of the raw amount, currency and locale used to format this Money.
+    ///
+    /// Intended for diagnosing formatting issues, the output format is not stable.
+    pub fn debug_breakdown(&self) -> String {
+        let format = LocalFormat::from_locale(self.currency.locale());
+        format!(
+            "amount: {}, scale: {}, code: {}, exponent: {}, locale: {}, digit_separator: {:?}, \
+             digit_separator_pattern: {}, exponent_separator: {:?}",
+            self.amount,
+            self.amount.scale(),
+            self.currency.code(),
+            self.currency.exponent(),
+            format.name,
+            format.digit_separator,
+            format.digit_separator_pattern,
+            format.exponent_separator,
+        )
     }
 
-    #[test]
-    fn money_addition_and_subtraction() {
+    /// Returns a formatted Money String using the given LocalFormat instead of the currency's Locale.
+    ///
+    /// This allows formatting with regional conventions that have no `Locale` variant.
+    pub fn format_with(&self, format: &LocalFormat) -> String {
+        Formatter::money(self, self.format_params(format))
+    }
+
+    /// Returns the formatted low and high Money joined by the separator (e.g. "$10.00 – $20.00").
+    ///
+    /// Errors if the bounds are in different currencies, or if low is greater than high.
+    pub fn format_range(
+        low: &Money<'a, T>,
+        high: &Money<'a, T>,
+        sep: &str,
+    ) -> Result<String, MoneyError> {
+        if low.currency != high.currency {
+            return Err(MoneyError::InvalidCurrency);
+        }
+        if low.amount > high.amount {
+            return Err(MoneyError::InvalidRange);
+        }
+        Ok(format!("{}{}{}", low, sep, high))
+    }
+
+    /// Returns a formatted Money String with the currency's name after the amount
+    /// (e.g. "1,000.00 United States Dollar").
+    pub fn format_with_name(&self) -> String {
+        self.format_with_suffix(Position::Name)
+    }
+
+    /// Returns a formatted Money String with the currency's code instead of its symbol
+    /// (e.g. "1.000,00 EUR"). The alternate flag of Display (`{:#}`) produces the same output.
+    pub fn to_string_with_code(&self) -> String {
+        self.format_with_suffix(Position::Code)
+    }
+
+    /// Returns a formatted Money String with the currency's code and no trailing zeros, which suits
+    /// high-precision crypto amounts (e.g. "0.5 BTC" instead of "₿0.50000000").
+    ///
+    /// The amount is rounded half-even to the currency's exponent before the zeros are trimmed.
+    pub fn to_crypto_string(&self) -> String {
+        let amount = self.round_to_currency(Round::HalfEven).amount.normalize();
+        Money::from_decimal(amount, self.currency).format_with_suffix(Position::Code)
+    }
+
+    /// Returns a formatted Money String using the currency's cash symbol instead of its
+    /// accounting symbol.
+    pub fn format_cash(&self) -> String {
+        let format = LocalFormat::from_locale(self.currency.locale());
+        let mut params = self.format_params(&format);
+        params.symbol = Some(self.currency.cash_symbol());
+        Formatter::money(self, params)
+    }
+
+    /// Returns a formatted Money String that omits the minor units when they are all zero
+    /// (e.g. "$100" for 100.00 USD, but "$100.10" for 100.10 USD).
+    pub fn to_string_trimmed(&self) -> String {
+        let format = LocalFormat::from_locale(self.currency.locale());
+        let mut params = self.format_params(&format);
+        params.trim_trailing_zeros = true;
+        Formatter::money(self, params)
+    }
+
+    /// Returns a formatted Money String in accounting style, where negative amounts are wrapped
+    /// in parentheses instead of signed (e.g. "($1,000.00)" for -1,000.00 USD).
+    pub fn to_accounting_string(&self) -> String {
+        let format = LocalFormat::from_locale(self.currency.locale());
+        let mut params = self.format_params(&format);
+        params.accounting = true;
+        Formatter::money(self, params)
+    }
+
+    /// Returns a formatted Money String described by a compact format specification, such as
+    /// "S#.###,##" for the symbol first with `.` grouping and two decimals after `,`.
+    ///
+    /// See `Params::from_dsl` for the syntax. Errors with `MoneyError::InvalidFormat` if the
+    /// specification cannot be parsed.
+    pub fn format_dsl(&self, spec: &str) -> Result<String, MoneyError> {
+        let mut params = Params::from_dsl(spec)?;
+        params.symbol = Some(self.currency.symbol());
+        params.code = Some(self.currency.code());
+        params.name = Some(self.currency.name());
+
+        // Pad to the requested decimals, since the Formatter only rounds
+        let decimals = params.rounding.unwrap_or(0);
+        let mut money = self.round(decimals, Round::HalfEven);
+        money.amount.rescale(decimals);
+        Ok(Formatter::money(&money, params))
+    }
+
+    /// Returns a formatted Money String in the currency's locale, with the given position after
+    /// the amount instead of the symbol.
+    fn format_with_suffix(&self, suffix: Position) -> String {
+        let format = LocalFormat::from_locale(self.currency.locale());
+        let mut params = self.format_params(&format);
+        params.positions = if format.trailing_sign {
+            vec![Position::Amount, Position::Sign, Position::Space, suffix]
+        } else {
+            vec![Position::Sign, Position::Amount, Position::Space, suffix]
+        };
+        Formatter::money(self, params)
+    }
+
+    /// Returns a formatted Money String using the given MoneyFormatter.
+    pub fn to_string_with(&self, formatter: &impl MoneyFormatter) -> String {
+        formatter.format(self)
+    }
+
+    /// Returns the parameters used to format this Money with the given LocalFormat.
+    fn format_params(&self, format: &LocalFormat) -> Params {
+        let currency = self.currency;
+
+        let mut format_params = Params {
+            digit_separator: format.digit_separator,
+            exponent_separator: format.exponent_separator,
+            separator_pattern: format.digit_separator_pattern(),
+            rounding: Some(currency.exponent()),
+            symbol: Some(currency.symbol()),
+            code: Some(currency.code()),
+            name: Some(currency.name()),
+            ..Default::default()
+        };
+
+        format_params.positions = match (currency.symbol_first(), format.trailing_sign) {
+            (true, false) => vec![Position::Sign, Position::Symbol, Position::Amount],
+            (false, false) => vec![Position::Sign, Position::Amount, Position::Symbol],
+            (true, true) => vec![Position::Symbol, Position::Amount, Position::Sign],
+            (false, true) => vec![Position::Amount, Position::Sign, Position::Symbol],
+        };
+        format_params
+    }
+
+    /// Returns the amount in minor units as a hexadecimal string (e.g. 1 ETH -> "0xde0b6b3a7640000").
+    ///
+    /// Errors with `MoneyError::PrecisionLoss` if the amount is not a whole number of minor units.
+    pub fn minor_units_hex(&self) -> Result<String, MoneyError> {
+        let scale = self.minor_unit_scale()?;
+        let minor_units = self.amount.checked_mul(scale).ok_or(MoneyError::Overflow)?;
+        if !minor_units.fract().is_zero() {
+            return Err(MoneyError::PrecisionLoss);
+        }
+
+        let minor_units = minor_units.to_i128().ok_or(MoneyError::Overflow)?;
+        if minor_units < 0 {
+            Ok(format!("-{:#x}", minor_units.unsigned_abs()))
+        } else {
+            Ok(format!("{:#x}", minor_units))
+        }
+    }
+
+    /// Converts this Money into the target Currency using a rate stored in the Exchange.
+    ///
+    /// Money already in the target Currency is returned as is. Errors with
+    /// `MoneyError::NoExchangeRate` if the Exchange has no rate for the pair.
+    #[cfg(feature = "std")]
+    pub fn convert_with(
+        &self,
+        exchange: &Exchange<'a, T>,
+        to: &'a T,
+    ) -> Result<Money<'a, T>, MoneyError> {
+        exchange.convert(self, to)
+    }
+
+    /// Returns the amount in major units of the currency (e.g. 12.34 USD -> 12.34).
+    pub fn in_major_units(&self) -> Decimal {
+        self.amount
+    }
+
+    /// Returns the amount in minor units of the currency without truncation (e.g. 12.34 USD -> 1234).
+    ///
+    /// Errors with `MoneyError::Overflow` if the amount in minor units is out of bounds.
+    pub fn in_minor_units(&self) -> Result<Decimal, MoneyError> {
+        self.amount
+            .checked_mul(self.minor_unit_scale()?)
+            .ok_or(MoneyError::Overflow)
+    }
+
+    /// Returns the amount as a whole number of minor units (e.g. 10.00 USD -> 1000).
+    ///
+    /// Errors with `MoneyError::PrecisionLoss` if the amount is not a whole number of minor units,
+    /// and `MoneyError::Overflow` if it does not fit in an i64.
+    pub fn to_minor_units_strict(&self) -> Result<i64, MoneyError> {
+        let minor_units = self
+            .amount
+            .checked_mul(self.minor_unit_scale()?)
+            .ok_or(MoneyError::Overflow)?;
+        if !minor_units.fract().is_zero() {
+            return Err(MoneyError::PrecisionLoss);
+        }
+        minor_units.to_i64().ok_or(MoneyError::Overflow)
+    }
+
+    /// Returns the amount as minor units, the currency's exponent and its code padded with zero
+    /// bytes (e.g. 10.00 USD -> (1000, 2, *b"USD\0")), which can be passed across an FFI boundary.
+    ///
+    /// Errors like `to_minor_units_strict`, or with `MoneyError::InvalidCurrency` if the code is
+    /// longer than four bytes.
+    pub fn to_ffi_repr(&self) -> Result<(i64, u32, [u8; 4]), MoneyError> {
+        let code = self.currency.code().as_bytes();
+        if code.len() > 4 {
+            return Err(MoneyError::InvalidCurrency);
+        }
+
+        let mut code_bytes = [0; 4];
+        code_bytes[..code.len()].copy_from_slice(code);
+        Ok((
+            self.to_minor_units_strict()?,
+            self.currency.exponent(),
+            code_bytes,
+        ))
+    }
+
+    /// Returns a formatted Money String rounded or padded to the given exponent instead of the
+    /// currency's (e.g. 10.00 USD at exponent 4 -> "$10.0000").
+    ///
+    /// Useful when amounts are held at a higher precision than the currency displays.
+    pub fn format_with_exponent(&self, exponent: u32) -> String {
+        let format = LocalFormat::from_locale(self.currency.locale());
+        let mut params = self.format_params(&format);
+        params.rounding = Some(exponent);
+
+        // Pad to the requested exponent, since the Formatter only rounds
+        let mut money = self.round(exponent, Round::HalfEven);
+        money.amount.rescale(exponent);
+        Formatter::money(&money, params)
+    }
+
+    /// Returns this Money with simple interest accrued, rounded to the currency's exponent.
+    ///
+    /// Interest is `principal * annual_rate * days / day_count`, where `day_count` is the number of
+    /// days in the year basis (e.g. 360 or 365). Panics if day_count is zero or the result is out
+    /// of bounds. Use `checked_accrue` to handle these cases as errors.
+    pub fn accrue(
+        &self,
+        annual_rate: Decimal,
+        days: u32,
+        day_count: u32,
+        round: Round,
+    ) -> Money<'a, T> {
+        match self.checked_accrue(annual_rate, days, day_count, round) {
+            Ok(money) => money,
+            Err(err) => panic!("interest cannot be accrued: {:?}", err),
+        }
+    }
+
+    /// Returns this Money with simple interest accrued like `accrue`, or
+    /// `MoneyError::DivisionByZero` if day_count is zero and `MoneyError::Overflow` if the result
+    /// is out of bounds.
+    pub fn checked_accrue(
+        &self,
+        annual_rate: Decimal,
+        days: u32,
+        day_count: u32,
+        round: Round,
+    ) -> Result<Money<'a, T>, MoneyError> {
+        if day_count == 0 {
+            return Err(MoneyError::DivisionByZero);
+        }
+
+        let amount = self
+            .amount
+            .checked_mul(annual_rate)
+            .and_then(|interest| interest.checked_mul(Decimal::from(days)))
+            .and_then(|interest| interest.checked_div(Decimal::from(day_count)))
+            .and_then(|interest| self.amount.checked_add(interest))
+            .ok_or(MoneyError::Overflow)?;
+        Ok(Money::from_decimal(amount, self.currency).round_to_currency(round))
+    }
+
+    /// The largest number of values yielded by `minor_unit_steps`.
+    pub const MAX_MINOR_UNIT_STEPS: usize = 10_000;
+
+    /// Returns an iterator over each whole minor unit from one up to this amount
+    /// (e.g. 0.03 USD -> 0.01, 0.02, 0.03 USD).
+    ///
+    /// Yields nothing for zero or negative amounts, or if the currency's exponent is above 28, and
+    /// stops after `MAX_MINOR_UNIT_STEPS` values to avoid runaway iteration over large amounts.
+    pub fn minor_unit_steps(&self) -> impl Iterator<Item = Money<'a, T>> {
+        let currency = self.currency;
+        let steps = match self.minor_unit_scale() {
+            Ok(scale) if self.is_positive() => self
+                .amount
+                .checked_mul(scale)
+                .and_then(|minor_units| minor_units.floor().to_usize())
+                .unwrap_or(usize::MAX),
+            _ => 0,
+        };
+        (1..=steps.min(Self::MAX_MINOR_UNIT_STEPS))
+            .map(move |step| Money::from_minor(step as i64, currency))
+    }
+}
+
+impl<T: FormattableCurrency + 'static> Money<'static, T> {
+    /// Creates a Money object given an amount string and a currency code.
+    ///
+    /// The currency is looked up in `T` with `find`, returning `MoneyError::InvalidCurrency` for an
+    /// unknown code, e.g. `Money::<iso::Currency>::from_str_with_code("10.00", "USD")`.
+    pub fn from_str_with_code(amount: &str, code: &str) -> Result<Money<'static, T>, MoneyError> {
+        let currency = T::find(code).ok_or(MoneyError::InvalidCurrency)?;
+        Money::from_str(amount, currency)
+    }
+
+    /// Creates a Money object from the representation returned by `to_ffi_repr`.
+    ///
+    /// Errors with `MoneyError::InvalidCurrency` if the code is not found in `T`, or if the exponent
+    /// does not match the currency's.
+    pub fn from_ffi_repr(repr: (i64, u32, [u8; 4])) -> Result<Money<'static, T>, MoneyError> {
+        let (minor_units, exponent, code) = repr;
+        let length = code.iter().position(|&b| b == 0).unwrap_or(code.len());
+        let code =
+            core::str::from_utf8(&code[..length]).map_err(|_| MoneyError::InvalidCurrency)?;
+
+        let currency = T::find(code).ok_or(MoneyError::InvalidCurrency)?;
+        if currency.exponent() != exponent {
+            return Err(MoneyError::InvalidCurrency);
+        }
+        Ok(Money::from_minor(minor_units, currency))
+    }
+}
+
+/// Strategies that can be used to round Money.
+///
+/// For more details, see [rust_decimal::RoundingStrategy]
+pub enum Round {
+    HalfUp,
+    HalfDown,
+    HalfEven,
+    /// Always rounds towards positive infinity.
+    Ceiling,
+    /// Always rounds towards negative infinity.
+    Floor,
+    /// Always rounds towards zero, truncating the amount.
+    TowardZero,
+    /// Always rounds away from zero.
+    AwayFromZero,
+}
+
+impl<'a, T: FormattableCurrency + FormattableCurrency> fmt::Display for Money<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            return pad(f, &self.to_string_with_code());
+        }
+        let format = LocalFormat::from_locale(self.currency.locale());
+        pad(f, &self.format_with(&format))
+    }
+}
+
+/// Writes a formatted amount with the formatter's width, fill and alignment.
+///
+/// Unlike `Formatter::pad`, precision is ignored, since truncating the string would change the amount.
+fn pad(f: &mut fmt::Formatter<'_>, formatted: &str) -> fmt::Result {
+    let padding = f
+        .width()
+        .unwrap_or(0)
+        .saturating_sub(formatted.chars().count());
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Right) => (padding, 0),
+        Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(fmt::Alignment::Left) | None => (0, padding),
+    };
+
+    let fill = f.fill();
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+    f.write_str(formatted)?;
+    for _ in 0..after {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+#[cfg(feature = "serde")]
+impl<'a, T: FormattableCurrency> Serialize for Money<'a, T> {
+    /// Serializes Money as its decimal amount string and currency code.
+    ///
+    /// For example, `{"amount": "-10.50", "currency": "USD"}`.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Money", 2)?;
+        state.serialize_field("amount", &self.amount.to_string())?;
+        state.serialize_field("currency", self.currency.code())?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: FormattableCurrency + 'static> Deserialize<'de> for Money<'static, T> {
+    /// Deserializes Money from its decimal amount string and currency code.
+    ///
+    /// The currency code is resolved with `FormattableCurrency::find`.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct RawMoney {
+            amount: String,
+            currency: String,
+        }
+
+        let raw = RawMoney::deserialize(deserializer)?;
+        let currency =
+            T::find(&raw.currency).ok_or_else(|| de::Error::custom(MoneyError::InvalidCurrency))?;
+        let amount = Decimal::from_str(&raw.amount)
+            .map_err(|_| de::Error::custom(MoneyError::InvalidAmount(None)))?;
+        Ok(Money::from_decimal(amount, currency))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::define_currency_set;
+
+    define_currency_set!(
+        test {
+            USD: {
+                code: "USD",
+                exponent: 2,
+                locale: EnUs,
+                minor_units: 100,
+                name: "USD",
+                symbol: "$",
+                symbol_first: true,
+            },
+            GBP : {
+                code: "GBP",
+                exponent: 2,
+                locale: EnUs,
+                minor_units: 1,
+                name: "British Pound",
+                symbol: "£",
+                symbol_first: true,
+            },
+            EUR : {
+                code: "EUR",
+                exponent: 2,
+                locale: EnEu,
+                minor_units: 1,
+                name: "Euro",
+                symbol: "€",
+                symbol_first: true,
+            },
+            INR : {
+                code: "INR",
+                exponent: 2,
+                locale: EnIn,
+                minor_units: 50,
+                name: "Indian Rupee",
+                symbol: "₹",
+                symbol_first: true,
+            },
+            BHD : {
+                code: "BHD",
+                exponent: 3,
+                locale: EnUs,
+                minor_units: 5,
+                name: "Bahraini Dinar",
+                symbol: "ب.د",
+                symbol_first: true,
+            },
+            AED : {
+                code: "AED",
+                exponent: 2,
+                locale: EnUs,
+                minor_units: 25,
+                name: "United Arab Emirates Dirham",
+                symbol: "د.إ",
+                symbol_first: false,
+            },
+            AUD : {
+                code: "AUD",
+                exponent: 2,
+                locale: EnUs,
+                minor_units: 5,
+                name: "Australian Dollar",
+                symbol: "$",
+                symbol_first: true,
+            },
+            JPY : {
+                code: "JPY",
+                exponent: 0,
+                locale: EnUs,
+                minor_units: 1,
+                name: "Japanese Yen",
+                symbol: "¥",
+                symbol_first: true,
+            },
+            BTC : {
+                code: "BTC",
+                exponent: 8,
+                locale: EnUs,
+                minor_units: 100_000_000,
+                name: "Bitcoin",
+                symbol: "₿",
+                symbol_first: true,
+            },
+            ETH : {
+                code: "ETH",
+                exponent: 18,
+                locale: EnUs,
+                minor_units: 1_000_000_000_000_000_000,
+                name: "Ethereum",
+                symbol: "ETH",
+                symbol_first: false,
+            },
+            USDC : {
+                code: "USDC",
+                exponent: 6,
+                locale: EnUs,
+                minor_units: 1_000_000,
+                name: "USD Coin",
+                symbol: "USDC",
+                symbol_first: false,
+            },
+            CLF : {
+                code: "CLF",
+                exponent: 4,
+                locale: EnEu,
+                minor_units: 5,
+                name: "Unidad de Fomento",
+                symbol: "UF",
+                symbol_first: true,
+            },
+            CHF : {
+                code: "CHF",
+                exponent: 2,
+                locale: DeCh,
+                minor_units: 5,
+                name: "Swiss Franc",
+                symbol: "Fr",
+                symbol_first: true,
+            },
+            CVE : {
+                cash_symbol: "$",
+                code: "CVE",
+                exponent: 2,
+                locale: EnEu,
+                minor_units: 100,
+                name: "Cape Verdean Escudo",
+                symbol: "Esc",
+                symbol_first: false,
+            },
+            DEM : {
+                code: "DEM",
+                exponent: 2,
+                locale: EnEuTrailingSign,
+                minor_units: 1,
+                name: "Deutsche Mark",
+                symbol: "DM",
+                symbol_first: false,
+            }
+        }
+    );
+
+    #[test]
+    fn money_major_minor() {
+        let _usd = test::find("USD"); // Prevents unused code warnings from the defined module.
+        let major_usd = Money::from_major(10, test::USD);
+        let minor_usd = Money::from_minor(1000, test::USD);
+        assert_eq!(major_usd, minor_usd);
+
+        // Both constructors produce the same scale, so they also format the same
+        let major_eur = Money::from_major(100, test::EUR);
+        let minor_eur = Money::from_minor(10_000, test::EUR);
+        assert_eq!(major_eur, minor_eur);
+        assert_eq!(major_eur.amount().scale(), minor_eur.amount().scale());
+        assert_eq!(major_eur.to_string(), "€100,00");
+        assert_eq!(major_eur.to_string(), minor_eur.to_string());
+    }
+
+    #[test]
+    fn money_from_minor_i128() {
+        let wei = 100 * 10i128.pow(18);
+        let money = Money::from_minor_i128(wei, test::ETH).unwrap();
+        assert_eq!(money, Money::from_major(100, test::ETH));
+        assert_eq!(money.amount().scale(), 18);
+
+        assert_eq!(
+            Money::from_minor_i128(-1_050, test::USD),
+            Ok(Money::from_minor(-1_050, test::USD))
+        );
+        assert_eq!(
+            Money::from_minor_i128(i128::MAX, test::USD),
+            Err(MoneyError::Overflow)
+        );
+    }
+
+    #[test]
+    fn money_from_string_parses_correctly() {
+        let expected_money = Money::from_minor(2999, test::GBP);
+        let money = Money::from_str("29.99", test::GBP).unwrap();
+        assert_eq!(money, expected_money);
+    }
+
+    #[test]
+    fn money_from_string_parses_correctly_for_64_bit_numbers() {
+        let expected_money = Money::from_major(i64::MAX, test::GBP);
+        let money = Money::from_str(&i64::MAX.to_string(), test::GBP).unwrap();
+        assert_eq!(money, expected_money);
+    }
+
+    #[test]
+    fn money_from_string_parses_amounts_beyond_32_bit_numbers() {
+        let expected_money = Money::from_major(3_000_000_000, test::GBP);
+        let money = Money::from_str("3,000,000,000", test::GBP).unwrap();
+        assert_eq!(money, expected_money);
+
+        let money = Money::from_str("3,000,000,000.00", test::GBP).unwrap();
+        assert_eq!(money, expected_money);
+    }
+
+    #[test]
+    fn money_from_string_rejects_unparseable_digits() {
+        let money = Money::from_str("1!0.00", test::GBP);
+        assert_eq!(money.unwrap_err(), MoneyError::InvalidAmount(None));
+
+        let money = Money::from_str("abc", test::GBP);
+        assert_eq!(money.unwrap_err(), MoneyError::InvalidAmount(None));
+
+        // Larger than a Decimal can hold
+        let money = Money::from_str("100000000000000000000000000000000", test::GBP);
+        assert_eq!(money.unwrap_err(), MoneyError::InvalidAmount(None));
+    }
+
+    #[test]
+    fn money_from_string_parses_signs() {
+        let expected_money = Money::from_minor(-300, test::GBP);
+        let money = Money::from_str("-3", test::GBP).unwrap();
+        assert_eq!(money, expected_money);
+
+        let expected_money = Money::from_minor(300, test::GBP);
+        let money = Money::from_str("+3", test::GBP).unwrap();
+        assert_eq!(money, expected_money);
+    }
+
+    #[test]
+    fn money_from_string_ignores_separators() {
+        let expected_money = Money::from_minor(100000000, test::GBP);
+        let money = Money::from_str("1,000,000", test::GBP).unwrap();
+        assert_eq!(money, expected_money);
+    }
+
+    #[test]
+    fn money_from_string_decimal_sanity() {
+        let money = Money::from_str("1,00.00", test::GBP);
+        assert_eq!(money.unwrap_err(), MoneyError::InvalidAmount(None));
+
+        let money = Money::from_str("1.00,00", test::EUR);
+        assert_eq!(money.unwrap_err(), MoneyError::InvalidAmount(None));
+
+        let money = Money::from_str("1.00.000,00", test::EUR);
+        assert_eq!(money.unwrap_err(), MoneyError::InvalidAmount(None));
+
+        let money = Money::from_str("1.00.000.000,00", test::EUR);
+        assert_eq!(money.unwrap_err(), MoneyError::InvalidAmount(None));
+
+        let money = Money::from_str("1,00.00", test::INR);
+        assert_eq!(money.unwrap_err(), MoneyError::InvalidAmount(None));
+
+        let money = Money::from_str("1.000.000.00", test::INR);
+        assert_eq!(money.unwrap_err(), MoneyError::InvalidAmount(None));
+    }
+
+    #[test]
+    fn money_from_string_parse_errs() {
+        // If the delimiter precede the separators
+        let money = Money::from_str("1.0000,000", test::GBP);
+        assert_eq!(money.unwrap_err(), MoneyError::InvalidAmount(None));
+
+        // If there are multiple delimiters
+        let money = Money::from_str("1.0000.000", test::GBP);
+        assert_eq!(money.unwrap_err(), MoneyError::InvalidAmount(None));
+
+        // If there is an unrecognized character
+        let money = Money::from_str("1.0000!000", test::GBP);
+        assert_eq!(money.unwrap_err(), MoneyError::InvalidAmount(None));
+
+        // If there are no characters other than separators
+        let exponent_separator_only = Money::from_str(",", test::GBP);
+        let amount_separator_only = Money::from_str(".", test::GBP);
+        let both_separators = Money::from_str(",,.", test::GBP);
+        assert_eq!(
+            exponent_separator_only.unwrap_err(),
+            MoneyError::InvalidAmount(None)
+        );
+        assert_eq!(
+            amount_separator_only.unwrap_err(),
+            MoneyError::InvalidAmount(None)
+        );
+        assert_eq!(
+            both_separators.unwrap_err(),
+            MoneyError::InvalidAmount(None)
+        );
+    }
+
+    #[test]
+    fn money_from_string_parses_high_exponent_currencies() {
+        let money = Money::from_str("1.11111111111", test::ETH).unwrap();
+        let expected_money = Money::from_decimal(Decimal::new(111_111_111_111, 11), test::ETH);
+        assert_eq!(money, expected_money);
+
+        let money = Money::from_str("1,000.000000000000000001", test::ETH).unwrap();
+        let expected_money = Money::from_decimal(
+            Decimal::from_i128_with_scale(1_000_000_000_000_000_000_001, 18),
+            test::ETH,
+        );
+        assert_eq!(money, expected_money);
+
+        let money = Money::from_str("0.12345678", test::BTC).unwrap();
+        assert_eq!(money, Money::from_minor(12_345_678, test::BTC));
+
+        let money = Money::from_str("21,000,000.00000001", test::BTC).unwrap();
+        assert_eq!(money, Money::from_minor(2_100_000_000_000_001, test::BTC));
+
+        let money = Money::from_str("1,234.567891", test::USDC).unwrap();
+        assert_eq!(money, Money::from_minor(1_234_567_891, test::USDC));
+    }
+
+    #[test]
+    fn money_from_string_rejects_signed_exponent() {
+        let money = Money::from_str("1.+5", test::USD);
+        assert_eq!(money.unwrap_err(), MoneyError::InvalidAmount(None));
+
+        let money = Money::from_str("1.-5", test::USD);
+        assert_eq!(money.unwrap_err(), MoneyError::InvalidAmount(None));
+
+        let money = Money::from_str("1.", test::USD);
+        assert_eq!(money.unwrap_err(), MoneyError::InvalidAmount(None));
+    }
+
+    #[test]
+    fn money_from_string_strict_rejects_excess_precision() {
+        let money = Money::from_str_strict("29.111", test::USD);
+        assert_eq!(
+            money.unwrap_err(),
+            MoneyError::ExcessPrecision {
+                provided: 3,
+                allowed: 2
+            }
+        );
+
+        let money = Money::from_str_strict("29.11", test::USD).unwrap();
+        assert_eq!(money, Money::from_minor(2_911, test::USD));
+
+        let money = Money::from_str_strict("29.111", test::BHD).unwrap();
+        assert_eq!(money, Money::from_minor(29_111, test::BHD));
+
+        // Parse errors are still reported as such
+        let money = Money::from_str_strict("1,00.00", test::USD);
+        assert_eq!(money.unwrap_err(), MoneyError::InvalidAmount(None));
+    }
+
+    #[test]
+    fn money_from_string_strict_reports_exponent_mismatch() {
+        let money = Money::from_str_strict("100.00", test::JPY);
+        assert_eq!(
+            money.unwrap_err(),
+            MoneyError::ExcessPrecision {
+                provided: 2,
+                allowed: 0
+            }
+        );
+
+        let money = Money::from_str_strict("100", test::JPY).unwrap();
+        assert_eq!(money, Money::from_major(100, test::JPY));
+
+        // The lenient parser keeps the extra precision
+        let money = Money::from_str("100.00", test::JPY).unwrap();
+        assert_eq!(money, Money::from_major(100, test::JPY));
+    }
+
+    #[test]
+    fn money_format_rounds_exponent() {
+        // // 19.999 rounds to 20 for USD
+        let money = Money::from_str("19.9999", test::USD).unwrap();
+        assert_eq!("$20.00", format!("{}", money));
+
+        // // 29.111 rounds to 29.11 for USD
+        let money = Money::from_str("29.111", test::USD).unwrap();
+        assert_eq!("$29.11", format!("{}", money));
+
+        // // 39.1155 rounds to 39.116 for BHD
+        let money = Money::from_str("39.1155", test::BHD).unwrap();
+        assert_eq!("ب.د39.116", format!("{}", money));
+    }
+
+    #[test]
+    fn money_addition_and_subtraction() {
         // Addition
         assert_eq!(
-            Money::from_major(2, test::USD),
-            Money::from_major(1, test::USD) + Money::from_major(1, test::USD)
+            Money::from_major(2, test::USD),
+            Money::from_major(1, test::USD) + Money::from_major(1, test::USD)
+        );
+        // Subtraction
+        assert_eq!(
+            Money::from_major(0, test::USD),
+            Money::from_major(1, test::USD) - Money::from_major(1, test::USD)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn money_addition_panics_on_different_currencies() {
+        let _no_op = Money::from_minor(100, test::USD) + Money::from_minor(100, test::GBP);
+    }
+
+    #[test]
+    #[should_panic]
+    fn money_subtraction_panics_on_different_currencies() {
+        let _no_op = Money::from_minor(100, test::USD) - Money::from_minor(100, test::GBP);
+    }
+
+    #[test]
+    #[should_panic]
+    fn money_add_assign_panics_on_different_currencies() {
+        let mut money = Money::from_minor(100, test::USD);
+        money += Money::from_minor(100, test::GBP);
+    }
+
+    #[test]
+    #[should_panic]
+    fn money_sub_assign_panics_on_different_currencies() {
+        let mut money = Money::from_minor(100, test::USD);
+        money -= Money::from_minor(100, test::GBP);
+    }
+
+    #[test]
+    fn money_multiplication_and_division() {
+        // Multiplication integer
+        assert_eq!(
+            Money::from_minor(200, test::USD),
+            Money::from_minor(100, test::USD) * 2
+        );
+        assert_eq!(
+            Money::from_minor(200, test::USD),
+            Money::from_minor(-100, test::USD) * -2
+        );
+        assert_eq!(
+            Money::from_minor(200, test::USD),
+            -2 * Money::from_minor(-100, test::USD)
+        );
+
+        // Multiplication decimal
+        assert_eq!(
+            Money::from_minor(200, test::USD),
+            Money::from_minor(100, test::USD) * Decimal::new(2, 0)
+        );
+        assert_eq!(
+            Money::from_minor(200, test::USD),
+            Money::from_minor(-100, test::USD) * Decimal::new(-2, 0)
+        );
+        assert_eq!(
+            Money::from_minor(200, test::USD),
+            Decimal::new(-2, 0) * Money::from_minor(-100, test::USD)
+        );
+        assert_eq!(
+            Money::from_minor(200, test::USD),
+            Money::from_minor(400, test::USD) * Decimal::new(5, 1)
+        );
+
+        // Division integer
+        assert_eq!(
+            Money::from_minor(200, test::USD),
+            Money::from_minor(400, test::USD) / 2
+        );
+        assert_eq!(
+            Money::from_minor(200, test::USD),
+            Money::from_minor(-400, test::USD) / -2
+        );
+        assert_eq!(
+            Money::from_minor(50, test::USD),
+            -1 / Money::from_minor(-200, test::USD)
+        );
+        assert_eq!(
+            Money::from_minor(200, test::USD),
+            Money::from_minor(-200, test::USD) / -1
+        );
+
+        // Division decimal
+        assert_eq!(
+            Money::from_minor(200, test::USD),
+            Money::from_minor(400, test::USD) / Decimal::new(2, 0)
+        );
+        assert_eq!(
+            Money::from_minor(200, test::USD),
+            Money::from_minor(-400, test::USD) / Decimal::new(-2, 0)
+        );
+        assert_eq!(
+            Money::from_minor(50, test::USD),
+            Decimal::new(-1, 0) / Money::from_minor(-200, test::USD)
+        );
+        assert_eq!(
+            Money::from_minor(200, test::USD),
+            Money::from_minor(-200, test::USD) / Decimal::new(-1, 0)
+        );
+        assert_eq!(
+            Money::from_minor(400, test::USD),
+            Money::from_minor(-200, test::USD) / Decimal::new(-5, 1)
+        );
+
+        //MulAssign integer
+        let mut money = Money::from_minor(100, test::USD);
+        money *= 2;
+        assert_eq!(Money::from_minor(200, test::USD), money);
+
+        //MulAssign decimal
+        let mut money = Money::from_minor(100, test::USD);
+        money *= Decimal::new(2, 0);
+        assert_eq!(Money::from_minor(200, test::USD), money);
+
+        //DivAssign integer
+        let mut money = Money::from_minor(100, test::USD);
+        money /= -2;
+        assert_eq!(Money::from_minor(-50, test::USD), money);
+
+        //DivAssign decimal
+        let mut money = Money::from_minor(100, test::USD);
+        money /= Decimal::new(-2, 0);
+        assert_eq!(Money::from_minor(-50, test::USD), money);
+    }
+
+    #[test]
+    fn money_negation() {
+        let money = Money::from_minor(100, test::USD);
+
+        assert_eq!(-money, Money::from_minor(-100, test::USD));
+    }
+
+    #[test]
+    fn money_comparison() {
+        // Greater Than
+        assert!(Money::from_minor(200, test::USD) > Money::from_minor(100, test::USD));
+        // Less Than
+        assert!(Money::from_minor(100, test::USD) < Money::from_minor(200, test::USD));
+        // Equals
+        assert!(Money::from_minor(100, test::USD) == Money::from_minor(100, test::USD));
+        assert!(Money::from_minor(100, test::USD) != Money::from_minor(100, test::GBP));
+        // is positive
+        assert!(Money::from_minor(100, test::USD).is_positive());
+        assert!(!Money::from_minor(0, test::USD).is_positive());
+        assert!(!Money::from_minor(-100, test::USD).is_positive());
+        // is zero
+        assert!(Money::from_minor(0, test::USD).is_zero());
+        assert!(!Money::from_minor(100, test::USD).is_zero());
+        assert!(!Money::from_minor(-100, test::USD).is_zero());
+        // is negative
+        assert!(Money::from_minor(-100, test::USD).is_negative());
+        assert!(!Money::from_minor(100, test::USD).is_negative());
+        assert!(!Money::from_minor(0, test::USD).is_negative());
+    }
+
+    #[test]
+    #[should_panic]
+    fn money_ops_greater_than_panics_on_different_currencies() {
+        assert!(Money::from_minor(100, test::USD) < Money::from_minor(100, test::GBP));
+    }
+
+    #[test]
+    #[should_panic]
+    fn money_ops_less_than_panics_on_different_currencies() {
+        assert!(Money::from_minor(100, test::USD) < Money::from_minor(100, test::GBP));
+    }
+
+    #[test]
+    fn money_allocate() {
+        let money = Money::from_minor(1_100, test::USD);
+        let allocated = money.allocate(vec![1, 1, 1]).unwrap();
+        let expected_results = vec![
+            Money::from_minor(400, test::USD),
+            Money::from_minor(400, test::USD),
+            Money::from_minor(300, test::USD),
+        ];
+        assert_eq!(expected_results, allocated);
+
+        // Error if the ratio vector is empty
+        let monies = Money::from_minor(100, test::USD).allocate(Vec::new());
+        assert_eq!(monies.unwrap_err(), MoneyError::InvalidRatio);
+
+        // Error if any ratio is zero
+        let monies = Money::from_minor(100, test::USD).allocate(vec![1, 0]);
+        assert_eq!(monies.unwrap_err(), MoneyError::InvalidRatio);
+
+        // Error if an intermediate share overflows
+        let monies = Money::from_decimal(Decimal::MAX, test::USD).allocate(vec![i32::MAX, 1]);
+        assert_eq!(monies.unwrap_err(), MoneyError::Overflow);
+    }
+
+    #[test]
+    fn money_allocate_to() {
+        let money = Money::from_minor(1_100, test::USD);
+        let monies = money.allocate_to(3).unwrap();
+        let expected_results = vec![
+            Money::from_minor(400, test::USD),
+            Money::from_minor(400, test::USD),
+            Money::from_minor(300, test::USD),
+        ];
+        assert_eq!(expected_results, monies);
+
+        let monies = Money::from_minor(100, test::USD).allocate_to(0);
+        assert_eq!(monies.unwrap_err(), MoneyError::InvalidRatio);
+    }
+
+    #[test]
+    fn money_split_by() {
+        let money = Money::from_major(350, test::USD);
+        let (shares, leftover) = money.split_by(&Money::from_major(100, test::USD)).unwrap();
+        assert_eq!(shares, vec![Money::from_major(100, test::USD); 3]);
+        assert_eq!(leftover, Money::from_major(50, test::USD));
+
+        // Shares larger than the money leave all of it over
+        let (shares, leftover) = money.split_by(&Money::from_major(500, test::USD)).unwrap();
+        assert!(shares.is_empty());
+        assert_eq!(leftover, money);
+
+        assert_eq!(
+            money.split_by(&Money::from_major(100, test::EUR)),
+            Err(MoneyError::InvalidCurrency)
+        );
+        assert_eq!(
+            money.split_by(&Money::from_major(0, test::USD)),
+            Err(MoneyError::DivisionByZero)
+        );
+
+        // Errors instead of allocating a share for every minor unit of a large amount
+        let money = Money::from_major(1_000_000_000, test::USD);
+        assert_eq!(
+            money.split_by(&Money::from_minor(1, test::USD)),
+            Err(MoneyError::Overflow)
+        );
+        let money = Money::from_major(100, test::USD);
+        let (shares, leftover) = money.split_by(&Money::from_minor(1, test::USD)).unwrap();
+        assert_eq!(shares.len(), Money::<test::Currency>::MAX_SPLIT_SHARES);
+        assert!(leftover.is_zero());
+    }
+
+    #[test]
+    fn money_fmt_separates_digits() {
+        let usd = Money::from_minor(0, test::USD); // Zero Dollars
+        let expected_usd_fmt = "$0.00";
+        assert_eq!(format!("{}", usd), expected_usd_fmt);
+
+        let usd = Money::from_minor(10_000_000, test::USD); // One Hundred Thousand Dollars
+        let expected_usd_fmt = "$100,000.00";
+        assert_eq!(format!("{}", usd), expected_usd_fmt);
+
+        let usd = Money::from_minor(-10_000_000, test::USD); // - One Hundred Thousand Dollars
+        let expected_usd_fmt = "-$100,000.00";
+        assert_eq!(format!("{}", usd), expected_usd_fmt);
+
+        let usd = Money::from_minor(100_000_000_000, test::USD); // 1 Billion Dollars
+        let expected_usd_fmt = "$1,000,000,000.00";
+        assert_eq!(format!("{}", usd), expected_usd_fmt);
+
+        let inr = Money::from_minor(10_000_000, test::INR); // 1 Lakh Rupees
+        let expected_inr_fmt = "₹1,00,000.00";
+        assert_eq!(format!("{}", inr), expected_inr_fmt);
+
+        let inr = Money::from_minor(-1_000_000_000, test::INR); // - 1 Crore Rupees
+        let expected_inr_fmt = "-₹1,00,00,000.00";
+        assert_eq!(format!("{}", inr), expected_inr_fmt);
+    }
+
+    #[test]
+    fn money_fmt_places_symbols_correctly() {
+        let money = Money::from_minor(0, test::USD);
+        let expected_fmt = "$0.00";
+        assert_eq!(format!("{}", money), expected_fmt);
+
+        let money = Money::from_minor(0, test::AED);
+        let expected_fmt = "0.00د.إ";
+        assert_eq!(format!("{}", money), expected_fmt);
+    }
+
+    #[test]
+    fn money_handles_four_decimal_currencies() {
+        let money = Money::from_minor(1_234_567, test::CLF);
+        assert_eq!(*money.amount(), Decimal::new(1_234_567, 4));
+        assert_eq!(money.amount().mantissa(), 1_234_567);
+        assert_eq!(money, Money::from_str("123,4567", test::CLF).unwrap());
+        assert_eq!(format!("{}", money), "UF123,4567");
+
+        let money = Money::from_str("1.000,12345", test::CLF).unwrap();
+        assert_eq!(format!("{}", money), "UF1.000,1234");
+        assert_eq!(format!("{}", -money), "-UF1.000,1234");
+
+        let money = Money::from_major(10, test::CLF);
+        assert_eq!(money, Money::from_minor(100_000, test::CLF));
+    }
+
+    #[test]
+    fn money_fmt_places_trailing_signs_correctly() {
+        let money = Money::from_minor(-10_000, test::DEM);
+        assert_eq!(format!("{}", money), "100,00-DM");
+
+        let money = Money::from_minor(10_000, test::DEM);
+        assert_eq!(format!("{}", money), "100,00DM");
+
+        let format = LocalFormat::from_locale(test::DEM.locale);
+        let money = Money::from_minor(-100_000, test::EUR);
+        assert_eq!(money.format_with(&format), "€1.000,00-");
+    }
+
+    #[test]
+    fn money_fmt_uses_correct_separators() {
+        let money = Money::from_minor(100_000, test::EUR);
+        let expected_fmt = "€1.000,00";
+        assert_eq!(format!("{}", money), expected_fmt);
+
+        let money = Money::from_minor(-100_000_000, test::CHF);
+        let expected_fmt = "-Fr1'000'000.00";
+        assert_eq!(format!("{}", money), expected_fmt);
+    }
+
+    #[test]
+    fn money_from_string_parses_swiss_separators() {
+        let money = Money::from_str("1'000'000.00", test::CHF).unwrap();
+        assert_eq!(money, Money::from_minor(100_000_000, test::CHF));
+
+        let money = Money::from_str("1'00'000.00", test::CHF);
+        assert_eq!(money.unwrap_err(), MoneyError::InvalidAmount(None));
+    }
+
+    #[test]
+    // Dividing 20 by 3 rounds to 6.67 in USD and 6.667 in BHD
+    fn money_precision_and_rounding() {
+        let expected_money = Money::from_minor(667, test::USD);
+        let mut money = Money::from_minor(2_000, test::USD);
+        money /= 3;
+        assert_eq!(money.round(2, Round::HalfEven), expected_money);
+
+        let expected_money = Money::from_minor(6_667, test::BHD);
+        let mut money = Money::from_minor(20_000, test::BHD);
+        money /= 3;
+        assert_eq!(money.round(3, Round::HalfEven), expected_money);
+    }
+
+    #[test]
+    fn money_round_directional_strategies() {
+        let positive = Money::from_str("10.001", test::USD).unwrap();
+        let negative = Money::from_str("-10.001", test::USD).unwrap();
+
+        assert_eq!(
+            positive.round(2, Round::Ceiling),
+            Money::from_minor(1_001, test::USD)
+        );
+        assert_eq!(
+            negative.round(2, Round::Ceiling),
+            Money::from_minor(-1_000, test::USD)
+        );
+
+        assert_eq!(
+            positive.round(2, Round::Floor),
+            Money::from_minor(1_000, test::USD)
+        );
+        assert_eq!(
+            negative.round(2, Round::Floor),
+            Money::from_minor(-1_001, test::USD)
+        );
+
+        assert_eq!(
+            positive.round(2, Round::TowardZero),
+            Money::from_minor(1_000, test::USD)
+        );
+        assert_eq!(
+            negative.round(2, Round::TowardZero),
+            Money::from_minor(-1_000, test::USD)
+        );
+
+        assert_eq!(
+            positive.round(2, Round::AwayFromZero),
+            Money::from_minor(1_001, test::USD)
+        );
+        assert_eq!(
+            negative.round(2, Round::AwayFromZero),
+            Money::from_minor(-1_001, test::USD)
+        );
+
+        // Amounts already at the precision are unchanged
+        let money = Money::from_minor(1_000, test::USD);
+        assert_eq!(money.round(2, Round::Ceiling), money);
+        assert_eq!(money.round(2, Round::AwayFromZero), money);
+    }
+
+    #[test]
+    fn money_round_to_currency() {
+        let mut money = Money::from_minor(2_000, test::USD);
+        money /= 3;
+        let rounded = money.round_to_currency(Round::HalfEven);
+        assert_eq!(rounded, Money::from_minor(667, test::USD));
+        assert_eq!(rounded.amount().scale(), 2);
+
+        let mut money = Money::from_minor(20_000, test::BHD);
+        money /= 3;
+        assert_eq!(
+            money.round_to_currency(Round::HalfDown),
+            Money::from_minor(6_667, test::BHD)
+        );
+
+        let money = Money::from_str("1234.5", test::JPY).unwrap();
+        assert_eq!(
+            money.round_to_currency(Round::HalfEven),
+            Money::from_major(1_234, test::JPY)
+        );
+        assert_eq!(
+            money.round_to_currency(Round::HalfUp),
+            Money::from_major(1_235, test::JPY)
+        );
+    }
+
+    #[test]
+    fn money_ops_uses_impl_copy() {
+        let money = Money::from_major(1, test::USD);
+        let _1st_derived_money = money * 3;
+        // if Money didn't impl Copy, this second multiplication would result in a compilation error
+        // because money would be moved (and consumed) in the 1st multiplication above:
+        let _2nd_derived_money = money * 3;
+    }
+
+    #[test]
+    fn money_representable_bounds() {
+        let max = Money::max_representable(test::USD);
+        let min = Money::min_representable(test::USD);
+        assert_eq!(*max.amount(), Decimal::MAX / Decimal::ONE_HUNDRED);
+        assert_eq!(max.amount().scale(), 2);
+        assert_eq!(*min.amount(), Decimal::MIN / Decimal::ONE_HUNDRED);
+        assert_eq!(min, -max);
+    }
+
+    #[test]
+    fn money_try_from_decimal_rejects_out_of_bounds() {
+        let max = Money::max_representable(test::USD);
+        assert_eq!(Money::try_from_decimal(*max.amount(), test::USD), Ok(max));
+
+        let money = Money::try_from_decimal(Decimal::MAX, test::USD);
+        assert_eq!(money.unwrap_err(), MoneyError::Overflow);
+
+        let money = Money::try_from_decimal(Decimal::MIN, test::USD);
+        assert_eq!(money.unwrap_err(), MoneyError::Overflow);
+    }
+
+    #[test]
+    fn money_try_from_decimal_rejects_excess_precision() {
+        let money = Money::try_from_decimal(Decimal::new(10_005, 3), test::USD);
+        assert_eq!(money.unwrap_err(), MoneyError::PrecisionLoss);
+
+        let money = Money::try_from_decimal(Decimal::new(1_005, 1), test::JPY);
+        assert_eq!(money.unwrap_err(), MoneyError::PrecisionLoss);
+
+        let money = Money::try_from_decimal(Decimal::new(10_005, 3), test::BHD);
+        assert_eq!(money, Ok(Money::from_minor(10_005, test::BHD)));
+
+        // Trailing zeros beyond the exponent lose nothing
+        let money = Money::try_from_decimal(Decimal::new(10_000, 3), test::USD);
+        assert_eq!(money, Ok(Money::from_minor(1_000, test::USD)));
+    }
+
+    #[test]
+    fn money_to_csv_field_quotes_separators() {
+        let money = Money::from_minor(100_000, test::USD);
+        assert_eq!(money.to_csv_field(), "\"$1,000.00\"");
+
+        let money = Money::from_minor(-100_000, test::EUR);
+        assert_eq!(money.to_csv_field(), "\"-€1.000,00\"");
+
+        let money = Money::from_minor(1_000, test::USD);
+        assert_eq!(money.to_csv_field(), "$10.00");
+    }
+
+    #[test]
+    fn money_percentages_of() {
+        let whole = Money::from_major(100, test::USD);
+        let parts = vec![
+            Money::from_major(25, test::USD),
+            Money::from_major(25, test::USD),
+            Money::from_major(50, test::USD),
+        ];
+        let expected = vec![
+            Decimal::new(25, 0),
+            Decimal::new(25, 0),
+            Decimal::new(50, 0),
+        ];
+        assert_eq!(whole.percentages_of(&parts).unwrap(), expected);
+
+        // Error if a part is in a different currency
+        let parts = vec![Money::from_major(25, test::GBP)];
+        assert_eq!(
+            whole.percentages_of(&parts).unwrap_err(),
+            MoneyError::InvalidCurrency
+        );
+
+        // Error if the whole is zero
+        let parts = vec![Money::from_major(25, test::USD)];
+        assert_eq!(
+            Money::from_major(0, test::USD)
+                .percentages_of(&parts)
+                .unwrap_err(),
+            MoneyError::DivisionByZero
+        );
+
+        // Error if a percentage is out of bounds
+        let whole = Money::from_decimal(Decimal::MAX, test::USD);
+        assert_eq!(
+            whole.percentages_of(&[whole]).unwrap_err(),
+            MoneyError::Overflow
+        );
+    }
+
+    #[test]
+    fn money_is_same_currency() {
+        let usd = Money::from_minor(100, test::USD);
+        assert!(usd.is_same_currency(&Money::from_minor(-500, test::USD)));
+        assert!(!usd.is_same_currency(&Money::from_minor(100, test::EUR)));
+
+        let copy = *test::USD;
+        assert!(usd.is_same_currency(&Money::from_minor(100, &copy)));
+    }
+
+    #[test]
+    fn money_min_max() {
+        let low = Money::from_minor(100, test::USD);
+        let high = Money::from_minor(200, test::USD);
+        assert_eq!(low.min(high), Ok(low));
+        assert_eq!(high.min(low), Ok(low));
+        assert_eq!(low.max(high), Ok(high));
+        assert_eq!(high.max(low), Ok(high));
+
+        // Equal amounts return self, keeping its scale
+        let scaled = Money::from_str("1.000", test::USD).unwrap();
+        assert_eq!(scaled.min(low).unwrap().amount().scale(), 3);
+        assert_eq!(scaled.max(low).unwrap().amount().scale(), 3);
+
+        let eur = Money::from_minor(100, test::EUR);
+        assert_eq!(low.min(eur), Err(MoneyError::InvalidCurrency));
+        assert_eq!(low.max(eur), Err(MoneyError::InvalidCurrency));
+    }
+
+    #[test]
+    fn money_clamp() {
+        let floor = Money::from_major(10, test::USD);
+        let ceiling = Money::from_major(20, test::USD);
+
+        let below = Money::from_major(5, test::USD);
+        assert_eq!(below.clamp(&floor, &ceiling), Ok(floor));
+        let above = Money::from_major(25, test::USD);
+        assert_eq!(above.clamp(&floor, &ceiling), Ok(ceiling));
+        let within = Money::from_minor(1_550, test::USD);
+        assert_eq!(within.clamp(&floor, &ceiling), Ok(within));
+        assert_eq!(floor.clamp(&floor, &floor), Ok(floor));
+
+        let eur = Money::from_major(15, test::EUR);
+        assert_eq!(
+            eur.clamp(&floor, &ceiling),
+            Err(MoneyError::InvalidCurrency)
+        );
+        assert_eq!(
+            within.clamp(&floor, &Money::from_major(20, test::EUR)),
+            Err(MoneyError::InvalidCurrency)
+        );
+        assert_eq!(
+            within.clamp(&ceiling, &floor),
+            Err(MoneyError::InvalidRange)
+        );
+    }
+
+    #[test]
+    fn money_ratio() {
+        let budget = Money::from_major(200, test::USD);
+        let item = Money::from_minor(5_000, test::USD);
+        assert_eq!(item.ratio(&budget), Ok(Decimal::new(25, 2)));
+        assert_eq!(budget.ratio(&item), Ok(Decimal::new(4, 0)));
+        assert_eq!(
+            Money::from_major(-1, test::USD).ratio(&Money::from_major(3, test::USD)),
+            Ok(Decimal::from(-1) / Decimal::from(3))
+        );
+
+        assert_eq!(
+            item.ratio(&Money::from_major(200, test::EUR)),
+            Err(MoneyError::InvalidCurrency)
+        );
+        assert_eq!(
+            item.ratio(&Money::from_major(0, test::USD)),
+            Err(MoneyError::DivisionByZero)
+        );
+    }
+
+    #[test]
+    fn money_difference_to() {
+        let target = Money::from_major(100, test::USD);
+        assert_eq!(
+            Money::from_major(60, test::USD).difference_to(&target),
+            Ok(Money::from_major(40, test::USD))
+        );
+        assert_eq!(
+            Money::from_major(120, test::USD).difference_to(&target),
+            Ok(Money::from_major(-20, test::USD))
+        );
+        assert_eq!(
+            Money::from_major(60, test::EUR).difference_to(&target),
+            Err(MoneyError::InvalidCurrency)
+        );
+    }
+
+    #[test]
+    fn money_percent_helpers() {
+        let money = Money::from_minor(1_999, test::USD);
+        assert_eq!(
+            money.percent(Decimal::new(15, 0)),
+            Money::from_str("2.9985", test::USD)
+        );
+        assert_eq!(
+            money.add_percent(Decimal::new(8_875, 3)),
+            Money::from_str("21.7641125", test::USD)
+        );
+        assert_eq!(
+            money.apply_discount(Decimal::new(25, 0)),
+            Money::from_str("14.9925", test::USD)
+        );
+
+        // Results are only rounded when the caller asks
+        assert_eq!(
+            money
+                .add_percent(Decimal::new(8_875, 3))
+                .unwrap()
+                .round_to_currency(Round::HalfEven),
+            Money::from_minor(2_176, test::USD)
+        );
+        assert_eq!(
+            money
+                .apply_discount(Decimal::new(25, 0))
+                .unwrap()
+                .round_to_currency(Round::HalfUp),
+            Money::from_minor(1_499, test::USD)
+        );
+
+        // Fractions of a minor unit are kept
+        let money = Money::from_minor(1, test::USD);
+        assert_eq!(
+            money.percent(Decimal::new(50, 0)),
+            Money::from_str("0.005", test::USD)
+        );
+
+        // Errors instead of panicking when the result is out of bounds
+        let money = Money::from_decimal(Decimal::MAX, test::USD);
+        assert_eq!(
+            money.percent(Decimal::ONE_THOUSAND),
+            Err(MoneyError::Overflow)
+        );
+        assert_eq!(money.add_percent(Decimal::TEN), Err(MoneyError::Overflow));
+        assert_eq!(
+            Money::from_decimal(Decimal::MIN, test::USD).apply_discount(Decimal::TEN),
+            Err(MoneyError::Overflow)
+        );
+    }
+
+    #[test]
+    fn money_rem() {
+        let money = Money::from_major(100, test::USD);
+        assert_eq!(money.rem(3), Ok(Money::from_minor(1, test::USD)));
+        assert_eq!(money.rem(4), Ok(Money::from_minor(0, test::USD)));
+        assert_eq!(money.rem(3).unwrap().currency(), test::USD);
+
+        let money = Money::from_minor(-1_000, test::BHD);
+        assert_eq!(money.rem(3), Ok(Money::from_minor(-1, test::BHD)));
+
+        // Shares plus the remainder reconstruct the original amount
+        let money = Money::from_minor(1_000, test::USD);
+        let share = Money::from_minor(333, test::USD);
+        assert_eq!(share * 3 + money.rem(3).unwrap(), money);
+
+        // Errors instead of panicking on invalid divisors and large amounts
+        assert_eq!(money.rem(0), Err(MoneyError::DivisionByZero));
+        let money = Money::from_decimal(Decimal::from(100_000_000_000i64), test::ETH);
+        assert_eq!(money.rem(3), Err(MoneyError::Overflow));
+    }
+
+    #[test]
+    fn money_lands_on_minor_unit() {
+        assert!(Money::from_minor(1_000, test::USD).lands_on_minor_unit());
+        assert!(Money::from_major(10, test::USD).lands_on_minor_unit());
+        assert!(!Money::from_str("10.005", test::USD)
+            .unwrap()
+            .lands_on_minor_unit());
+        assert!(Money::from_str("10.005", test::BHD)
+            .unwrap()
+            .lands_on_minor_unit());
+    }
+
+    #[test]
+    fn money_lands_on_cash_denomination() {
+        assert!(Money::from_minor(125, test::AUD)
+            .lands_on_cash_denomination()
+            .unwrap());
+        assert!(!Money::from_minor(123, test::AUD)
+            .lands_on_cash_denomination()
+            .unwrap());
+        assert!(Money::from_minor(123, test::GBP)
+            .lands_on_cash_denomination()
+            .unwrap());
+        assert!(Money::from_minor(-150, test::AED)
+            .lands_on_cash_denomination()
+            .unwrap());
+        assert!(!Money::from_minor(-110, test::AED)
+            .lands_on_cash_denomination()
+            .unwrap());
+    }
+
+    #[test]
+    fn money_debug_breakdown() {
+        let money = Money::from_major(100, test::EUR);
+        assert_eq!(
+            money.debug_breakdown(),
+            "amount: 100.00, scale: 2, code: EUR, exponent: 2, locale: en-eu, digit_separator: '.', \
+             digit_separator_pattern: 3, 3, 3, exponent_separator: ','"
+        );
+
+        let money = Money::from_minor(10_000, test::INR);
+        let breakdown = money.debug_breakdown();
+        assert!(breakdown.contains("scale: 2"));
+        assert!(breakdown.contains("locale: en-in"));
+    }
+
+    #[test]
+    fn money_format_with_custom_local_format() {
+        let format = LocalFormat {
+            name: "fr-fr",
+            digit_separator: ' ',
+            digit_separator_pattern: "3, 3, 3",
+            exponent_separator: ',',
+            trailing_sign: false,
+        };
+        let money = Money::from_minor(-123_456_789, test::USD);
+        assert_eq!(money.format_with(&format), "-$1 234 567,89");
+
+        let format = LocalFormat {
+            name: "zh-cn",
+            digit_separator: ',',
+            digit_separator_pattern: "4, 4, 4",
+            exponent_separator: '.',
+            trailing_sign: false,
+        };
+        let money = Money::from_minor(123_456_789, test::AED);
+        assert_eq!(money.format_with(&format), "123,4567.89د.إ");
+
+        // The currency's own locale produces the same output as Display
+        let format = LocalFormat::from_locale(test::EUR.locale);
+        let money = Money::from_minor(100_000, test::EUR);
+        assert_eq!(money.format_with(&format), format!("{}", money));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn money_serde_round_trip() {
+        let money = Money::from_minor(-1_050, test::USD);
+        let json = serde_json::to_string(&money).unwrap();
+        assert_eq!(json, r#"{"amount":"-10.50","currency":"USD"}"#);
+
+        let deserialized: Money<test::Currency> = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, money);
+        assert_eq!(deserialized.amount().scale(), 2);
+
+        let money = Money::from_str("1.000000000000000001", test::ETH).unwrap();
+        let json = serde_json::to_string(&money).unwrap();
+        let deserialized: Money<test::Currency> = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, money);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn money_serde_rejects_unknown_currency_and_invalid_amount() {
+        let json = r#"{"amount":"10.50","currency":"FAKE"}"#;
+        let err = serde_json::from_str::<Money<test::Currency>>(json).unwrap_err();
+        assert!(err.to_string().contains("Currency was not valid"));
+
+        let json = r#"{"amount":"ten","currency":"USD"}"#;
+        let err = serde_json::from_str::<Money<test::Currency>>(json).unwrap_err();
+        assert!(err.to_string().contains("Amount not parsable"));
+    }
+
+    #[test]
+    fn money_minor_units_hex() {
+        let money = Money::from_major(1, test::ETH);
+        assert_eq!(money.minor_units_hex().unwrap(), "0xde0b6b3a7640000");
+
+        let money = Money::from_minor(-255, test::USD);
+        assert_eq!(money.minor_units_hex().unwrap(), "-0xff");
+
+        let money = Money::from_major(0, test::USD);
+        assert_eq!(money.minor_units_hex().unwrap(), "0x0");
+
+        // Fractions of a wei cannot be encoded
+        let money = Money::from_decimal(Decimal::new(1, 19), test::ETH);
+        assert_eq!(
+            money.minor_units_hex().unwrap_err(),
+            MoneyError::PrecisionLoss
+        );
+
+        let money = Money::from_decimal(Decimal::MAX, test::ETH);
+        assert_eq!(money.minor_units_hex().unwrap_err(), MoneyError::Overflow);
+    }
+
+    #[test]
+    fn money_in_major_and_minor_units() {
+        let money = Money::from_minor(1_234, test::USD);
+        assert_eq!(money.in_major_units(), Decimal::new(1_234, 2));
+        assert_eq!(money.in_minor_units().unwrap(), Decimal::new(1_234, 0));
+
+        let money = Money::from_minor(1_234, test::BHD);
+        assert_eq!(money.in_major_units(), Decimal::new(1_234, 3));
+        assert_eq!(money.in_minor_units().unwrap(), Decimal::new(1_234, 0));
+
+        // Sub-minor-unit precision is preserved
+        let money = Money::from_str("12.345", test::USD).unwrap();
+        assert_eq!(money.in_minor_units().unwrap(), Decimal::new(12_345, 1));
+    }
+
+    #[test]
+    fn money_format_with_exponent() {
+        let money = Money::from_str("1234.5678", test::USD).unwrap();
+        assert_eq!(money.format_with_exponent(0), "$1,235");
+        assert_eq!(money.format_with_exponent(2), "$1,234.57");
+        assert_eq!(money.format_with_exponent(4), "$1,234.5678");
+        assert_eq!(format!("{}", money), money.format_with_exponent(2));
+
+        // Amounts with fewer decimals are padded to the exponent
+        let money = Money::from_minor(1_000, test::USD);
+        assert_eq!(money.format_with_exponent(4), "$10.0000");
+        let money = Money::from_minor(1_000, test::JPY);
+        assert_eq!(money.format_with_exponent(2), "¥1,000.00");
+    }
+
+    #[test]
+    fn money_minor_unit_scale() {
+        assert_eq!(
+            Money::from_major(1, test::JPY).minor_unit_scale().unwrap(),
+            Decimal::ONE
+        );
+        assert_eq!(
+            Money::from_major(1, test::USD).minor_unit_scale().unwrap(),
+            Decimal::ONE_HUNDRED
+        );
+        assert_eq!(
+            Money::from_major(1, test::BHD).minor_unit_scale().unwrap(),
+            Decimal::ONE_THOUSAND
+        );
+        assert_eq!(
+            Money::from_major(1, test::ETH).minor_unit_scale().unwrap(),
+            Decimal::from(1_000_000_000_000_000_000u64)
+        );
+
+        // Zero and three decimal currencies convert between major and minor units symmetrically
+        let money = Money::from_minor(1_234, test::JPY);
+        assert_eq!(
+            money.in_minor_units().unwrap() / money.minor_unit_scale().unwrap(),
+            money.in_major_units()
+        );
+        let money = Money::from_minor(1_234, test::BHD);
+        assert_eq!(
+            money.in_minor_units().unwrap() / money.minor_unit_scale().unwrap(),
+            money.in_major_units()
+        );
+        // Exponents beyond a Decimal's largest scale are reported as errors
+        define_currency_set!(
+            tiny {
+                TINY: {
+                    code: "TINY",
+                    exponent: 29,
+                    locale: EnUs,
+                    minor_units: 1,
+                    name: "Tiny",
+                    symbol: "T",
+                    symbol_first: true,
+                }
+            }
+        );
+        let money = Money::from_decimal(Decimal::ONE, tiny::TINY);
+        assert_eq!(money.minor_unit_scale(), Err(MoneyError::Overflow));
+        assert_eq!(money.in_minor_units(), Err(MoneyError::Overflow));
+        assert_eq!(
+            money.lands_on_cash_denomination(),
+            Err(MoneyError::Overflow)
+        );
+        assert_eq!(money.rem(3), Err(MoneyError::Overflow));
+        assert_eq!(money.minor_unit_steps().count(), 0);
+    }
+
+    #[test]
+    fn money_checked_abs() {
+        let money = Money::from_minor(-1_050, test::USD);
+        assert_eq!(money.checked_abs(), Ok(Money::from_minor(1_050, test::USD)));
+
+        let money = Money::from_minor(1_050, test::USD);
+        assert_eq!(money.checked_abs(), Ok(money));
+
+        let money = Money::min_representable(test::USD);
+        assert_eq!(money.checked_abs(), Ok(Money::max_representable(test::USD)));
+    }
+
+    #[test]
+    fn money_round_to_cash_denomination() {
+        let money = Money::from_minor(123, test::CHF).round_to_cash_denomination();
+        assert_eq!(money, Money::from_minor(125, test::CHF));
+        assert_eq!(money.amount().scale(), 2);
+
+        let money = Money::from_minor(-122, test::CHF).round_to_cash_denomination();
+        assert_eq!(money, Money::from_minor(-120, test::CHF));
+
+        // Midpoints round to the even multiple by default
+        let money = Money::from_str("10.125", test::AED).unwrap();
+        assert_eq!(
+            money.round_to_cash_denomination(),
+            Money::from_minor(1_000, test::AED)
+        );
+        let money = Money::from_str("10.375", test::AED).unwrap();
+        assert_eq!(
+            money.round_to_cash_denomination(),
+            Money::from_minor(1_050, test::AED)
+        );
+
+        let money = Money::from_str("1.025", test::AUD).unwrap();
+        assert_eq!(
+            money.round_to_cash_denomination(),
+            Money::from_minor(100, test::AUD)
+        );
+        assert_eq!(
+            money.round_to_cash_denomination_with(Round::HalfUp),
+            Money::from_minor(105, test::AUD)
+        );
+        assert_eq!(
+            money.round_to_cash_denomination_with(Round::HalfDown),
+            Money::from_minor(100, test::AUD)
+        );
+        assert!(money
+            .round_to_cash_denomination()
+            .lands_on_cash_denomination()
+            .unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot be rounded to the cash denomination: Overflow")]
+    fn money_round_to_cash_denomination_panics_on_overflow() {
+        let money = Money::from_decimal(Decimal::MAX, test::AUD);
+        let _no_op = money.round_to_cash_denomination();
+    }
+
+    #[test]
+    fn money_format_range() {
+        let low = Money::from_minor(1_000, test::USD);
+        let high = Money::from_minor(200_000, test::USD);
+        assert_eq!(
+            Money::format_range(&low, &high, " – ").unwrap(),
+            "$10.00 – $2,000.00"
+        );
+        assert_eq!(
+            Money::format_range(&low, &low, "-").unwrap(),
+            "$10.00-$10.00"
+        );
+
+        let low = Money::from_minor(100_050, test::EUR);
+        let high = Money::from_minor(200_000, test::EUR);
+        assert_eq!(
+            Money::format_range(&low, &high, " – ").unwrap(),
+            "€1.000,50 – €2.000,00"
+        );
+    }
+
+    #[test]
+    fn money_format_range_errors() {
+        let low = Money::from_minor(1_000, test::USD);
+        let high = Money::from_minor(2_000, test::GBP);
+        assert_eq!(
+            Money::format_range(&low, &high, " – ").unwrap_err(),
+            MoneyError::InvalidCurrency
+        );
+
+        let high = Money::from_minor(500, test::USD);
+        assert_eq!(
+            Money::format_range(&low, &high, " – ").unwrap_err(),
+            MoneyError::InvalidRange
+        );
+    }
+
+    #[test]
+    fn money_from_str_with_code() {
+        let money = Money::<test::Currency>::from_str_with_code("1,000.50", "USD").unwrap();
+        assert_eq!(money, Money::from_minor(100_050, test::USD));
+
+        // The amount is parsed with the located currency's locale
+        let money = Money::<test::Currency>::from_str_with_code("1.000,50", "EUR").unwrap();
+        assert_eq!(money, Money::from_minor(100_050, test::EUR));
+
+        assert_eq!(
+            Money::<test::Currency>::from_str_with_code("1,000.50", "FAKE").unwrap_err(),
+            MoneyError::InvalidCurrency
+        );
+        assert_eq!(
+            Money::<test::Currency>::from_str_with_code("ten", "USD").unwrap_err(),
+            MoneyError::InvalidAmount(None)
+        );
+    }
+
+    #[test]
+    fn money_from_string_reports_parse_error_reason() {
+        let reason = |amount: &str| match Money::from_str(amount, test::USD).unwrap_err() {
+            MoneyError::InvalidAmount(reason) => reason,
+            err => panic!("unexpected error {:?}", err),
+        };
+
+        assert_eq!(reason("1,00.00"), Some(AmountError::InvalidGrouping));
+        assert_eq!(reason("10.0x"), Some(AmountError::NonNumeric));
+        assert_eq!(reason("1x.00"), Some(AmountError::NonNumeric));
+        assert_eq!(
+            reason("1.00.00"),
+            Some(AmountError::MultipleExponentSeparators)
+        );
+        assert_eq!(reason("10."), Some(AmountError::MissingFraction));
+        assert_eq!(
+            reason("1000000000000000000000000000000"),
+            Some(AmountError::TooManyDigits)
+        );
+    }
+
+    #[test]
+    fn money_format_with_name() {
+        let money = Money::from_minor(100_000, test::GBP);
+        assert_eq!(money.format_with_name(), "1,000.00 British Pound");
+
+        let money = Money::from_minor(-100_000, test::EUR);
+        assert_eq!(money.format_with_name(), "-1.000,00 Euro");
+
+        let money = Money::from_minor(-100_000, test::DEM);
+        assert_eq!(money.format_with_name(), "1.000,00- Deutsche Mark");
+    }
+
+    #[test]
+    fn money_accrue() {
+        let principal = Money::from_minor(1_000_000, test::USD);
+        let rate = Decimal::new(5, 2);
+
+        // 10,000 * 0.05 * 30 / 360 = 41.666...
+        let total = principal.accrue(rate, 30, 360, Round::HalfEven);
+        assert_eq!(total, Money::from_minor(1_004_167, test::USD));
+        assert_eq!(total.amount().scale(), 2);
+
+        // 10,000 * 0.05 * 30 / 365 = 41.095...
+        let total = principal.accrue(rate, 30, 365, Round::HalfEven);
+        assert_eq!(total, Money::from_minor(1_004_110, test::USD));
+
+        assert_eq!(principal.accrue(rate, 0, 360, Round::HalfEven), principal);
+
+        let principal = Money::from_major(1_000, test::JPY);
+        let total = principal.accrue(Decimal::new(1, 2), 1, 360, Round::HalfUp);
+        assert_eq!(total, Money::from_major(1_000, test::JPY));
+
+        // The checked variant errors instead of panicking
+        let principal = Money::from_minor(1_000_000, test::USD);
+        assert_eq!(
+            principal.checked_accrue(rate, 30, 360, Round::HalfEven),
+            Ok(Money::from_minor(1_004_167, test::USD))
+        );
+        assert_eq!(
+            principal.checked_accrue(rate, 30, 0, Round::HalfEven),
+            Err(MoneyError::DivisionByZero)
+        );
+        let principal = Money::from_decimal(Decimal::MAX, test::USD);
+        assert_eq!(
+            principal.checked_accrue(rate, 365, 360, Round::HalfEven),
+            Err(MoneyError::Overflow)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "interest cannot be accrued: DivisionByZero")]
+    fn money_accrue_panics_on_zero_day_count() {
+        let principal = Money::from_minor(1_000_000, test::USD);
+        let _no_op = principal.accrue(Decimal::new(5, 2), 30, 0, Round::HalfEven);
+    }
+
+    #[test]
+    fn money_from_string_handles_leading_zeros() {
+        let money = Money::from_str("007.50", test::USD).unwrap();
+        assert_eq!(money, Money::from_minor(750, test::USD));
+        assert_eq!(money.to_string(), "$7.50");
+
+        let money = Money::from_str("0000", test::USD).unwrap();
+        assert_eq!(money, Money::from_minor(0, test::USD));
+        assert_eq!(money.to_string(), "$0.00");
+
+        let money = Money::from_str("0000.00", test::USD).unwrap();
+        assert_eq!(money.to_string(), "$0.00");
+
+        let money = Money::from_str("000,000.01", test::USD).unwrap();
+        assert_eq!(money, Money::from_minor(1, test::USD));
+        assert_eq!(money.to_string(), "$0.01");
+
+        let money =
+            Money::from_str("-000000000000000000000000000000000000012.5", test::USD).unwrap();
+        assert_eq!(money, Money::from_minor(-1_250, test::USD));
+    }
+
+    #[test]
+    fn money_to_string_with_custom_formatter() {
+        struct SpanFormatter;
+
+        impl MoneyFormatter for SpanFormatter {
+            fn format<T: FormattableCurrency>(&self, money: &Money<T>) -> String {
+                format!(
+                    "<span class=\"amount\">{}</span> {}",
+                    money.amount(),
+                    money.currency().code()
+                )
+            }
+        }
+
+        let money = Money::from_minor(-100_050, test::USD);
+        assert_eq!(
+            money.to_string_with(&SpanFormatter),
+            "<span class=\"amount\">-1000.50</span> USD"
         );
-        // Subtraction
+
+        let money = Money::from_minor(100_050, test::EUR);
         assert_eq!(
-            Money::from_major(0, test::USD),
-            Money::from_major(1, test::USD) - Money::from_major(1, test::USD)
+            money.to_string_with(&crate::LocaleFormatter),
+            money.to_string()
         );
     }
 
     #[test]
-    #[should_panic]
-    fn money_addition_panics_on_different_currencies() {
-        let _no_op = Money::from_minor(100, test::USD) + Money::from_minor(100, test::GBP);
+    fn money_to_string_with_code() {
+        let money = Money::from_minor(10_000, test::EUR);
+        assert_eq!(money.to_string_with_code(), "100,00 EUR");
+        assert_eq!(format!("{:#}", money), "100,00 EUR");
+        assert_eq!(format!("{}", money), "€100,00");
+
+        let money = Money::from_minor(-100_000, test::USD);
+        assert_eq!(money.to_string_with_code(), "-1,000.00 USD");
+        assert_eq!(format!("{:#}", money), "-1,000.00 USD");
+
+        let money = Money::from_minor(-100_000, test::DEM);
+        assert_eq!(money.to_string_with_code(), "1.000,00- DEM");
     }
 
     #[test]
-    #[should_panic]
-    fn money_subtraction_panics_on_different_currencies() {
-        let _no_op = Money::from_minor(100, test::USD) - Money::from_minor(100, test::GBP);
-    }
+    fn money_to_crypto_string() {
+        let money = Money::from_minor(50_000_000, test::BTC);
+        assert_eq!(money.to_crypto_string(), "0.5 BTC");
 
-    #[test]
-    #[should_panic]
-    fn money_add_assign_panics_on_different_currencies() {
-        let mut money = Money::from_minor(100, test::USD);
-        money += Money::from_minor(100, test::GBP);
+        let money = Money::from_major(1_000, test::USDC);
+        assert_eq!(money.to_crypto_string(), "1,000 USDC");
+
+        // Full precision is kept up to the exponent
+        let money = Money::from_str("1.000000000000000001", test::ETH).unwrap();
+        assert_eq!(money.to_crypto_string(), "1.000000000000000001 ETH");
+        let money = Money::from_str("0.100000001", test::BTC).unwrap();
+        assert_eq!(money.to_crypto_string(), "0.1 BTC");
+
+        let money = Money::from_minor(-12_345, test::BTC);
+        assert_eq!(money.to_crypto_string(), "-0.00012345 BTC");
     }
 
     #[test]
-    #[should_panic]
-    fn money_sub_assign_panics_on_different_currencies() {
-        let mut money = Money::from_minor(100, test::USD);
-        money -= Money::from_minor(100, test::GBP);
+    fn money_fmt_honors_width_and_alignment() {
+        let money = Money::from_minor(100_000, test::USD);
+        assert_eq!(format!("{:>12}", money), "   $1,000.00");
+        assert_eq!(format!("{:<12}|", money), "$1,000.00   |");
+        assert_eq!(format!("{:*^13}", money), "**$1,000.00**");
+        assert_eq!(format!("{:>#15}", money), "   1,000.00 USD");
+
+        // Multibyte symbols are padded by character count
+        let money = Money::from_minor(100, test::AED);
+        assert_eq!(format!("{:>9}", money), "  1.00د.إ");
+
+        // A width narrower than the output does not truncate it
+        let money = Money::from_minor(100_000, test::USD);
+        assert_eq!(format!("{:3}", money), "$1,000.00");
+
+        // Precision does not truncate the amount
+        let money = Money::from_minor(1_000, test::USD);
+        assert_eq!(format!("{:.2}", money), "$10.00");
+        assert_eq!(format!("{:>8.1}", money), "  $10.00");
+        assert_eq!(format!("{:#.0}", money), "10.00 USD");
     }
 
     #[test]
-    fn money_multiplication_and_division() {
-        // Multiplication integer
+    fn money_to_plain_string() {
         assert_eq!(
-            Money::from_minor(200, test::USD),
-            Money::from_minor(100, test::USD) * 2
+            Money::from_minor(123_450, test::USD).to_plain_string(),
+            "1234.50"
         );
         assert_eq!(
-            Money::from_minor(200, test::USD),
-            Money::from_minor(-100, test::USD) * -2
+            Money::from_major(1_234, test::USD).to_plain_string(),
+            "1234.00"
         );
+        assert_eq!(Money::from_minor(-5, test::USD).to_plain_string(), "-0.05");
+        assert_eq!(Money::from_major(500, test::JPY).to_plain_string(), "500");
         assert_eq!(
-            Money::from_minor(200, test::USD),
-            -2 * Money::from_minor(-100, test::USD)
+            Money::from_minor(1_234_567, test::BHD).to_plain_string(),
+            "1234.567"
         );
 
-        // Multiplication decimal
+        // Locale separators and symbols are not used
         assert_eq!(
-            Money::from_minor(200, test::USD),
-            Money::from_minor(100, test::USD) * Decimal::new(2, 0)
+            Money::from_minor(123_450, test::EUR).to_plain_string(),
+            "1234.50"
         );
         assert_eq!(
-            Money::from_minor(200, test::USD),
-            Money::from_minor(-100, test::USD) * Decimal::new(-2, 0)
+            Money::from_minor(-123_450, test::DEM).to_plain_string(),
+            "-1234.50"
+        );
+
+        let money = Money::from_str("1234.565", test::USD).unwrap();
+        assert_eq!(money.to_plain_string(), "1234.56");
+    }
+
+    #[test]
+    fn money_eq_rounded() {
+        let precise = Money::from_decimal(Decimal::new(10_001, 3), test::USD);
+        let minor = Money::from_minor(1_000, test::USD);
+        assert!(precise != minor);
+        assert!(precise.eq_rounded(&minor));
+        assert!(minor.eq_rounded(&precise));
+
+        let different = Money::from_minor(1_001, test::USD);
+        assert!(precise != different);
+        assert!(!precise.eq_rounded(&different));
+
+        let precise = Money::from_decimal(Decimal::new(10_001, 3), test::BHD);
+        assert!(!precise.eq_rounded(&Money::from_minor(10_000, test::BHD)));
+
+        let gbp = Money::from_minor(1_000, test::GBP);
+        assert!(!minor.eq_rounded(&gbp));
+    }
+
+    #[test]
+    fn money_to_minor_units_strict() {
+        let money = Money::from_minor(1_000, test::USD);
+        assert_eq!(money.to_minor_units_strict(), Ok(1_000));
+
+        let money = Money::from_major(-10, test::USD);
+        assert_eq!(money.to_minor_units_strict(), Ok(-1_000));
+
+        let money = Money::from_major(500, test::JPY);
+        assert_eq!(money.to_minor_units_strict(), Ok(500));
+
+        let money = Money::from_str("10.005", test::USD).unwrap();
+        assert_eq!(
+            money.to_minor_units_strict(),
+            Err(MoneyError::PrecisionLoss)
         );
+
+        let money = Money::from_minor(i64::MAX, test::USD) + Money::from_minor(1, test::USD);
+        assert_eq!(money.to_minor_units_strict(), Err(MoneyError::Overflow));
+
+        let money = Money::from_decimal(Decimal::MAX, test::USD);
+        assert_eq!(money.to_minor_units_strict(), Err(MoneyError::Overflow));
+
+        let money = Money::from_minor(i64::MIN, test::USD);
+        assert_eq!(money.to_minor_units_strict(), Ok(i64::MIN));
+    }
+
+    #[test]
+    fn money_ffi_repr_round_trips() {
+        let usd = Money::from_minor(-123_456, test::USD);
+        let repr = usd.to_ffi_repr().unwrap();
+        assert_eq!(repr, (-123_456, 2, *b"USD\0"));
+        assert_eq!(Money::from_ffi_repr(repr), Ok(usd));
+
+        let jpy = Money::from_major(5_000, test::JPY);
+        let repr = jpy.to_ffi_repr().unwrap();
+        assert_eq!(repr, (5_000, 0, *b"JPY\0"));
+        assert_eq!(Money::from_ffi_repr(repr), Ok(jpy));
+
+        // Amounts beyond i64 minor units overflow
+        let money = Money::from_decimal(Decimal::from(i64::MAX), test::USD);
+        assert_eq!(money.to_ffi_repr(), Err(MoneyError::Overflow));
+
         assert_eq!(
-            Money::from_minor(200, test::USD),
-            Decimal::new(-2, 0) * Money::from_minor(-100, test::USD)
+            Money::<test::Currency>::from_ffi_repr((100, 2, *b"XYZ\0")),
+            Err(MoneyError::InvalidCurrency)
         );
         assert_eq!(
-            Money::from_minor(200, test::USD),
-            Money::from_minor(400, test::USD) * Decimal::new(5, 1)
+            Money::<test::Currency>::from_ffi_repr((100, 0, *b"USD\0")),
+            Err(MoneyError::InvalidCurrency)
         );
+    }
 
-        // Division integer
+    #[test]
+    fn money_to_string_trimmed() {
         assert_eq!(
-            Money::from_minor(200, test::USD),
-            Money::from_minor(400, test::USD) / 2
+            Money::from_minor(10_000, test::USD).to_string_trimmed(),
+            "$100"
         );
         assert_eq!(
-            Money::from_minor(200, test::USD),
-            Money::from_minor(-400, test::USD) / -2
+            Money::from_minor(10_010, test::USD).to_string_trimmed(),
+            "$100.10"
         );
         assert_eq!(
-            Money::from_minor(50, test::USD),
-            -1 / Money::from_minor(-200, test::USD)
+            Money::from_minor(-10_000, test::EUR).to_string_trimmed(),
+            "-€100"
         );
         assert_eq!(
-            Money::from_minor(200, test::USD),
-            Money::from_minor(-200, test::USD) / -1
+            Money::from_minor(10_000, test::BHD).to_string_trimmed(),
+            "ب.د10"
         );
-
-        // Division decimal
         assert_eq!(
-            Money::from_minor(200, test::USD),
-            Money::from_minor(400, test::USD) / Decimal::new(2, 0)
+            Money::from_minor(10_050, test::BHD).to_string_trimmed(),
+            "ب.د10.050"
         );
         assert_eq!(
-            Money::from_minor(200, test::USD),
-            Money::from_minor(-400, test::USD) / Decimal::new(-2, 0)
+            Money::from_major(500, test::JPY).to_string_trimmed(),
+            "¥500"
         );
+    }
+
+    #[test]
+    fn money_format_dsl() {
+        let money = Money::from_minor(-123_456_789, test::USD);
+        assert_eq!(money.format_dsl("S#.###,##").unwrap(), "-$1.234.567,89");
+        assert_eq!(money.format_dsl("#,###.## C").unwrap(), "-1,234,567.89 USD");
+        assert_eq!(money.format_dsl("C #'###.#").unwrap(), "-USD 1'234'567.9");
+        assert_eq!(money.format_dsl("# ###,###S").unwrap(), "-1 234 567,890$");
+        assert_eq!(money.format_dsl("S#,###.").unwrap(), "-$1,234,568");
+        assert_eq!(money.format_dsl("#").unwrap(), "-1234568");
+
+        let invalid = [
+            "", "S", "##,#.##", "#.###.##", "#..##", "X#.##", "#.##X", ".##", "#A###",
+        ];
+        for spec in invalid {
+            assert_eq!(
+                money.format_dsl(spec),
+                Err(MoneyError::InvalidFormat),
+                "{}",
+                spec
+            );
+        }
+    }
+
+    #[test]
+    fn money_to_accounting_string() {
         assert_eq!(
-            Money::from_minor(50, test::USD),
-            Decimal::new(-1, 0) / Money::from_minor(-200, test::USD)
+            Money::from_minor(-100_000, test::USD).to_accounting_string(),
+            "($1,000.00)"
         );
         assert_eq!(
-            Money::from_minor(200, test::USD),
-            Money::from_minor(-200, test::USD) / Decimal::new(-1, 0)
+            Money::from_minor(100_000, test::USD).to_accounting_string(),
+            "$1,000.00"
         );
         assert_eq!(
-            Money::from_minor(400, test::USD),
-            Money::from_minor(-200, test::USD) / Decimal::new(-5, 1)
+            Money::from_minor(-100_000, test::EUR).to_accounting_string(),
+            "(€1.000,00)"
         );
-
-        //MulAssign integer
-        let mut money = Money::from_minor(100, test::USD);
-        money *= 2;
-        assert_eq!(Money::from_minor(200, test::USD), money);
-
-        //MulAssign decimal
-        let mut money = Money::from_minor(100, test::USD);
-        money *= Decimal::new(2, 0);
-        assert_eq!(Money::from_minor(200, test::USD), money);
-
-        //DivAssign integer
-        let mut money = Money::from_minor(100, test::USD);
-        money /= -2;
-        assert_eq!(Money::from_minor(-50, test::USD), money);
-
-        //DivAssign decimal
-        let mut money = Money::from_minor(100, test::USD);
-        money /= Decimal::new(-2, 0);
-        assert_eq!(Money::from_minor(-50, test::USD), money);
     }
 
     #[test]
-    fn money_negation() {
-        let money = Money::from_minor(100, test::USD);
+    #[cfg(feature = "std")]
+    fn money_convert_with_exchange() {
+        let mut exchange = Exchange::new();
+        let rate = crate::ExchangeRate::new(test::USD, test::EUR, Decimal::new(857, 3)).unwrap();
+        exchange.set_rate(&rate);
 
-        assert_eq!(-money, Money::from_minor(-100, test::USD));
+        let money = Money::from_minor(1_000, test::USD);
+        assert_eq!(
+            money.convert_with(&exchange, test::EUR),
+            Ok(Money::from_minor(857, test::EUR))
+        );
+        assert_eq!(money.convert_with(&exchange, test::USD), Ok(money));
+        assert_eq!(
+            money.convert_with(&exchange, test::GBP),
+            Err(MoneyError::NoExchangeRate)
+        );
     }
 
     #[test]
-    fn money_comparison() {
-        // Greater Than
-        assert!(Money::from_minor(200, test::USD) > Money::from_minor(100, test::USD));
-        // Less Than
-        assert!(Money::from_minor(100, test::USD) < Money::from_minor(200, test::USD));
-        // Equals
-        assert!(Money::from_minor(100, test::USD) == Money::from_minor(100, test::USD));
-        assert!(Money::from_minor(100, test::USD) != Money::from_minor(100, test::GBP));
-        // is positive
-        assert!(Money::from_minor(100, test::USD).is_positive());
-        assert!(!Money::from_minor(0, test::USD).is_positive());
-        assert!(!Money::from_minor(-100, test::USD).is_positive());
-        // is zero
-        assert!(Money::from_minor(0, test::USD).is_zero());
-        assert!(!Money::from_minor(100, test::USD).is_zero());
-        assert!(!Money::from_minor(-100, test::USD).is_zero());
-        // is negative
-        assert!(Money::from_minor(-100, test::USD).is_negative());
-        assert!(!Money::from_minor(100, test::USD).is_negative());
-        assert!(!Money::from_minor(0, test::USD).is_negative());
+    fn money_from_major_checked() {
+        let money = Money::from_major_checked(1_000, test::USD).unwrap();
+        assert_eq!(money, Money::from_minor(100_000, test::USD));
+        assert_eq!(money.amount().scale(), 2);
+        assert_eq!(money.to_string(), "$1,000.00");
+
+        let money = Money::from_major_checked(-1_000, test::JPY).unwrap();
+        assert_eq!(money, Money::from_minor(-1_000, test::JPY));
+        assert_eq!(money.amount().scale(), 0);
+
+        let money = Money::from_major_checked(1_000, test::BHD).unwrap();
+        assert_eq!(money, Money::from_minor(1_000_000, test::BHD));
+        assert_eq!(money.amount().scale(), 3);
+
+        let money = Money::from_major_checked(i64::MAX, test::ETH);
+        assert_eq!(money.unwrap_err(), MoneyError::Overflow);
     }
 
     #[test]
-    #[should_panic]
-    fn money_ops_greater_than_panics_on_different_currencies() {
-        assert!(Money::from_minor(100, test::USD) < Money::from_minor(100, test::GBP));
-    }
+    fn money_minor_unit_steps() {
+        let steps: Vec<_> = Money::from_minor(3, test::USD).minor_unit_steps().collect();
+        assert_eq!(
+            steps,
+            vec![
+                Money::from_minor(1, test::USD),
+                Money::from_minor(2, test::USD),
+                Money::from_minor(3, test::USD),
+            ]
+        );
 
-    #[test]
-    #[should_panic]
-    fn money_ops_less_than_panics_on_different_currencies() {
-        assert!(Money::from_minor(100, test::USD) < Money::from_minor(100, test::GBP));
-    }
+        let steps: Vec<_> = Money::from_major(2, test::JPY).minor_unit_steps().collect();
+        assert_eq!(
+            steps,
+            vec![
+                Money::from_major(1, test::JPY),
+                Money::from_major(2, test::JPY)
+            ]
+        );
 
-    #[test]
-    fn money_allocate() {
-        let money = Money::from_minor(1_100, test::USD);
-        let allocated = money.allocate(vec![1, 1, 1]).unwrap();
-        let expected_results = vec![
-            Money::from_minor(400, test::USD),
-            Money::from_minor(400, test::USD),
-            Money::from_minor(300, test::USD),
-        ];
-        assert_eq!(expected_results, allocated);
+        // Partial minor units are not reached
+        let money = Money::from_str("0.025", test::USD).unwrap();
+        assert_eq!(money.minor_unit_steps().count(), 2);
 
-        // Error if the ratio vector is empty
-        let monies = Money::from_minor(100, test::USD).allocate(Vec::new());
-        assert_eq!(monies.unwrap_err(), MoneyError::InvalidRatio);
+        assert_eq!(
+            Money::from_minor(0, test::USD).minor_unit_steps().count(),
+            0
+        );
+        assert_eq!(
+            Money::from_minor(-3, test::USD).minor_unit_steps().count(),
+            0
+        );
 
-        // Error if any ratio is zero
-        let monies = Money::from_minor(100, test::USD).allocate(vec![1, 0]);
-        assert_eq!(monies.unwrap_err(), MoneyError::InvalidRatio);
+        // Large amounts are capped
+        let money = Money::from_major(1_000_000_000, test::USD);
+        assert_eq!(
+            money.minor_unit_steps().count(),
+            Money::<test::Currency>::MAX_MINOR_UNIT_STEPS
+        );
+        let money = Money::from_decimal(Decimal::MAX, test::USD);
+        let last = money.minor_unit_steps().last().unwrap();
+        assert_eq!(last, Money::from_minor(10_000, test::USD));
     }
 
     #[test]
-    fn money_allocate_to() {
-        let money = Money::from_minor(1_100, test::USD);
-        let monies = money.allocate_to(3).unwrap();
-        let expected_results = vec![
-            Money::from_minor(400, test::USD),
-            Money::from_minor(400, test::USD),
-            Money::from_minor(300, test::USD),
-        ];
-        assert_eq!(expected_results, monies);
-
-        let monies = Money::from_minor(100, test::USD).allocate_to(0);
-        assert_eq!(monies.unwrap_err(), MoneyError::InvalidRatio);
+    fn money_hashes_consistently_with_eq() {
+        use std::collections::HashSet;
+
+        let mut amounts = HashSet::new();
+        amounts.insert(Money::from_minor(1_000, test::USD));
+        amounts.insert(Money::from_major(10, test::USD));
+        amounts.insert(Money::from_str("10.000", test::USD).unwrap());
+        assert_eq!(amounts.len(), 1);
+
+        amounts.insert(Money::from_minor(1_000, test::GBP));
+        assert_eq!(amounts.len(), 2);
+
+        // Sub-minor-unit amounts are distinct until rounded
+        let precise = Money::from_str("10.001", test::USD).unwrap();
+        assert!(amounts.insert(precise));
+        assert!(!amounts.insert(precise.round_to_currency(Round::HalfEven)));
     }
 
     #[test]
-    fn money_fmt_separates_digits() {
-        let usd = Money::from_minor(0, test::USD); // Zero Dollars
-        let expected_usd_fmt = "$0.00";
-        assert_eq!(format!("{}", usd), expected_usd_fmt);
-
-        let usd = Money::from_minor(10_000_000, test::USD); // One Hundred Thousand Dollars
-        let expected_usd_fmt = "$100,000.00";
-        assert_eq!(format!("{}", usd), expected_usd_fmt);
-
-        let usd = Money::from_minor(-10_000_000, test::USD); // - One Hundred Thousand Dollars
-        let expected_usd_fmt = "-$100,000.00";
-        assert_eq!(format!("{}", usd), expected_usd_fmt);
-
-        let usd = Money::from_minor(100_000_000_000, test::USD); // 1 Billion Dollars
-        let expected_usd_fmt = "$1,000,000,000.00";
-        assert_eq!(format!("{}", usd), expected_usd_fmt);
-
-        let inr = Money::from_minor(10_000_000, test::INR); // 1 Lakh Rupees
-        let expected_inr_fmt = "₹1,00,000.00";
-        assert_eq!(format!("{}", inr), expected_inr_fmt);
-
-        let inr = Money::from_minor(-1_000_000_000, test::INR); // - 1 Crore Rupees
-        let expected_inr_fmt = "-₹1,00,00,000.00";
-        assert_eq!(format!("{}", inr), expected_inr_fmt);
+    fn money_format_cash() {
+        let money = Money::from_minor(150_000, test::CVE);
+        assert_eq!(money.to_string(), "1.500,00Esc");
+        assert_eq!(money.format_cash(), "1.500,00$");
+        assert_eq!(test::CVE.cash_symbol(), "$");
+        assert_eq!(test::CVE.symbol(), "Esc");
+
+        // Currencies without a cash symbol use their symbol
+        let money = Money::from_minor(-150_000, test::USD);
+        assert_eq!(test::USD.cash_symbol(), "$");
+        assert_eq!(money.format_cash(), money.to_string());
     }
 
     #[test]
-    fn money_fmt_places_symbols_correctly() {
-        let money = Money::from_minor(0, test::USD);
-        let expected_fmt = "$0.00";
-        assert_eq!(format!("{}", money), expected_fmt);
+    fn money_from_string_accepts_apostrophe_and_space_grouping() {
+        let expected = Money::from_major(1_000_000, test::USD);
+        assert_eq!(Money::from_str("1'000'000", test::USD).unwrap(), expected);
+        assert_eq!(Money::from_str("1 000 000", test::USD).unwrap(), expected);
+        assert_eq!(
+            Money::from_str("1\u{a0}000\u{a0}000", test::USD).unwrap(),
+            expected
+        );
+        assert_eq!(
+            Money::from_str("1\u{202f}000\u{202f}000", test::USD).unwrap(),
+            expected
+        );
 
-        let money = Money::from_minor(0, test::AED);
-        let expected_fmt = "0.00د.إ";
-        assert_eq!(format!("{}", money), expected_fmt);
+        let money = Money::from_str("-1 000 000,50", test::EUR).unwrap();
+        assert_eq!(money, Money::from_minor(-100_000_050, test::EUR));
+
+        let money = Money::from_str("1'234.50", test::CHF).unwrap();
+        assert_eq!(money, Money::from_minor(123_450, test::CHF));
+
+        // Grouping must still follow the locale's pattern
+        let money = Money::from_str("1 00 000", test::USD);
+        assert_eq!(money.unwrap_err(), MoneyError::InvalidAmount(None));
+        let money = Money::from_str("12 34 567", test::INR).unwrap();
+        assert_eq!(money, Money::from_major(1_234_567, test::INR));
     }
 
     #[test]
-    fn money_fmt_uses_correct_separators() {
-        let money = Money::from_minor(100_000, test::EUR);
-        let expected_fmt = "€1.000,00";
-        assert_eq!(format!("{}", money), expected_fmt);
+    fn money_normalized() {
+        let minor = Money::from_minor(10_000, test::EUR);
+        let major = Money::from_major(100, test::EUR).normalized();
+        assert_eq!(major.amount().scale(), 2);
+        assert_eq!(major.to_string(), minor.to_string());
+        assert_eq!(major.amount().to_string(), minor.amount().to_string());
+
+        let money = Money::from_str("100,000", test::EUR).unwrap().normalized();
+        assert_eq!(money.amount().scale(), 2);
+
+        // Sub-minor-unit precision is kept without trailing zeros
+        let money = Money::from_str("100,00100", test::EUR)
+            .unwrap()
+            .normalized();
+        assert_eq!(money.amount().to_string(), "100.001");
+
+        let money = Money::from_major(100, test::JPY).normalized();
+        assert_eq!(money.amount().scale(), 0);
+        let money = Money::from_str("-0.0", test::USD).unwrap().normalized();
+        assert_eq!(money.amount().to_string(), "0.00");
     }
 
     #[test]
-    // Dividing 20 by 3 rounds to 6.67 in USD and 6.667 in BHD
-    fn money_precision_and_rounding() {
-        let expected_money = Money::from_minor(667, test::USD);
-        let mut money = Money::from_minor(2_000, test::USD);
-        money /= 3;
-        assert_eq!(money.round(2, Round::HalfEven), expected_money);
+    fn money_round_to_denomination_checked() {
+        let money = Money::from_minor(123, test::AUD);
+        assert_eq!(
+            money.round_to_denomination_checked(Round::HalfEven),
+            Ok(Money::from_minor(125, test::AUD))
+        );
+        assert_eq!(
+            money.round_to_denomination_checked(Round::Floor),
+            Ok(Money::from_minor(120, test::AUD))
+        );
 
-        let expected_money = Money::from_minor(6_667, test::BHD);
-        let mut money = Money::from_minor(20_000, test::BHD);
-        money /= 3;
-        assert_eq!(money.round(3, Round::HalfEven), expected_money);
-    }
+        let money = Money::from_str("100000000000000000000000.03", test::AUD).unwrap();
+        assert_eq!(
+            money.round_to_denomination_checked(Round::HalfEven),
+            Ok(Money::from_str("100000000000000000000000.05", test::AUD).unwrap())
+        );
 
-    #[test]
-    fn money_ops_uses_impl_copy() {
-        let money = Money::from_major(1, test::USD);
-        let _1st_derived_money = money * 3;
-        // if Money didn't impl Copy, this second multiplication would result in a compilation error
-        // because money would be moved (and consumed) in the 1st multiplication above:
-        let _2nd_derived_money = money * 3;
+        let money = Money::from_decimal(Decimal::MAX, test::AUD);
+        assert_eq!(
+            money.round_to_denomination_checked(Round::HalfEven),
+            Err(MoneyError::Overflow)
+        );
     }
 }
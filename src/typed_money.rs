@@ -0,0 +1,302 @@
+use crate::currency::FormattableCurrency;
+use crate::{Money, MoneyError};
+
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use rust_decimal::Decimal;
+
+/// A type that stands for a single currency, so amounts in it can be checked at compile time.
+///
+/// `define_currency_set!` generates markers for currencies declared with a `marker` name. Markers
+/// for other currencies can be written by hand:
+///
+/// ```
+/// use rusty_money::{iso, CurrencyMarker};
+///
+/// struct Usd;
+///
+/// impl CurrencyMarker for Usd {
+///     type Currency = iso::Currency;
+///
+///     fn currency() -> &'static iso::Currency {
+///         iso::USD
+///     }
+/// }
+/// ```
+pub trait CurrencyMarker {
+    type Currency: FormattableCurrency + 'static;
+
+    /// Returns the currency that this marker stands for.
+    fn currency() -> &'static Self::Currency;
+}
+
+/// Represents an amount of a currency that is known at compile time.
+///
+/// Unlike Money, the currency is part of the type, so mixing currencies fails to compile instead
+/// of panicking, and no currency reference is stored:
+///
+/// ```compile_fail
+/// use rusty_money::{define_currency_set, TypedMoney};
+///
+/// define_currency_set!(
+///     bank {
+///         USD: {
+///             marker: Usd,
+///             code: "USD",
+///             exponent: 2,
+///             locale: EnUs,
+///             minor_units: 1,
+///             name: "United States Dollar",
+///             symbol: "$",
+///             symbol_first: true,
+///         },
+///         EUR: {
+///             marker: Eur,
+///             code: "EUR",
+///             exponent: 2,
+///             locale: EnEu,
+///             minor_units: 1,
+///             name: "Euro",
+///             symbol: "€",
+///             symbol_first: true,
+///         }
+///     }
+/// );
+///
+/// let total = TypedMoney::<bank::Usd>::from_minor(100) + TypedMoney::<bank::Eur>::from_minor(100);
+/// ```
+///
+/// Conversions to and from Money go through `to_money` and `try_from_money`.
+pub struct TypedMoney<C: CurrencyMarker> {
+    amount: Decimal,
+    currency: PhantomData<C>,
+}
+
+impl<C: CurrencyMarker> TypedMoney<C> {
+    /// Creates a TypedMoney object given an amount in minor units (e.g. 1000 -> 10.00 in USD).
+    pub fn from_minor(amount: i64) -> TypedMoney<C> {
+        TypedMoney::from_decimal(*Money::from_minor(amount, C::currency()).amount())
+    }
+
+    /// Creates a TypedMoney object given an amount in major units (e.g. 1000 -> 1,000.00 in USD).
+    pub fn from_major(amount: i64) -> TypedMoney<C> {
+        TypedMoney::from_decimal(*Money::from_major(amount, C::currency()).amount())
+    }
+
+    /// Creates a TypedMoney object given a decimal amount.
+    pub fn from_decimal(amount: Decimal) -> TypedMoney<C> {
+        TypedMoney {
+            amount,
+            currency: PhantomData,
+        }
+    }
+
+    /// Converts a Money into a TypedMoney, erroring if it is not in the marker's currency.
+    pub fn try_from_money(money: &Money<'_, C::Currency>) -> Result<TypedMoney<C>, MoneyError> {
+        if money.currency() != C::currency() {
+            return Err(MoneyError::InvalidCurrency);
+        }
+        Ok(TypedMoney::from_decimal(*money.amount()))
+    }
+
+    /// Returns a Money with the same amount, whose currency is checked at runtime.
+    pub fn to_money(&self) -> Money<'static, C::Currency> {
+        Money::from_decimal(self.amount, C::currency())
+    }
+
+    /// Returns a reference to the Decimal amount.
+    pub fn amount(&self) -> &Decimal {
+        &self.amount
+    }
+
+    /// Returns the currency that the marker stands for.
+    pub fn currency(&self) -> &'static C::Currency {
+        C::currency()
+    }
+}
+
+impl<C: CurrencyMarker> Clone for TypedMoney<C> {
+    fn clone(&self) -> TypedMoney<C> {
+        *self
+    }
+}
+
+impl<C: CurrencyMarker> Copy for TypedMoney<C> {}
+
+impl<C: CurrencyMarker> PartialEq for TypedMoney<C> {
+    fn eq(&self, other: &TypedMoney<C>) -> bool {
+        self.amount == other.amount
+    }
+}
+
+impl<C: CurrencyMarker> Eq for TypedMoney<C> {}
+
+impl<C: CurrencyMarker> PartialOrd for TypedMoney<C> {
+    fn partial_cmp(&self, other: &TypedMoney<C>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C: CurrencyMarker> Ord for TypedMoney<C> {
+    fn cmp(&self, other: &TypedMoney<C>) -> Ordering {
+        self.amount.cmp(&other.amount)
+    }
+}
+
+/// Hashes the amount and currency code, consistently with Money.
+impl<C: CurrencyMarker> Hash for TypedMoney<C> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_money().hash(state);
+    }
+}
+
+impl<C: CurrencyMarker> fmt::Debug for TypedMoney<C> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TypedMoney")
+            .field("amount", &self.amount)
+            .field("currency", &C::currency().code())
+            .finish()
+    }
+}
+
+impl<C: CurrencyMarker> fmt::Display for TypedMoney<C> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.to_money(), f)
+    }
+}
+
+impl<C: CurrencyMarker> Add for TypedMoney<C> {
+    type Output = TypedMoney<C>;
+    fn add(self, other: TypedMoney<C>) -> TypedMoney<C> {
+        TypedMoney::from_decimal(self.amount + other.amount)
+    }
+}
+
+impl<C: CurrencyMarker> AddAssign for TypedMoney<C> {
+    fn add_assign(&mut self, other: TypedMoney<C>) {
+        self.amount += other.amount;
+    }
+}
+
+impl<C: CurrencyMarker> Sub for TypedMoney<C> {
+    type Output = TypedMoney<C>;
+    fn sub(self, other: TypedMoney<C>) -> TypedMoney<C> {
+        TypedMoney::from_decimal(self.amount - other.amount)
+    }
+}
+
+impl<C: CurrencyMarker> SubAssign for TypedMoney<C> {
+    fn sub_assign(&mut self, other: TypedMoney<C>) {
+        self.amount -= other.amount;
+    }
+}
+
+impl<C: CurrencyMarker> Neg for TypedMoney<C> {
+    type Output = TypedMoney<C>;
+    fn neg(self) -> TypedMoney<C> {
+        TypedMoney::from_decimal(-self.amount)
+    }
+}
+
+impl<C: CurrencyMarker> Mul<Decimal> for TypedMoney<C> {
+    type Output = TypedMoney<C>;
+    fn mul(self, rhs: Decimal) -> TypedMoney<C> {
+        TypedMoney::from_decimal(self.amount * rhs)
+    }
+}
+
+impl<C: CurrencyMarker> Mul<i64> for TypedMoney<C> {
+    type Output = TypedMoney<C>;
+    fn mul(self, rhs: i64) -> TypedMoney<C> {
+        TypedMoney::from_decimal(self.amount * Decimal::from(rhs))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::define_currency_set;
+
+    define_currency_set!(
+        test {
+            USD: {
+                marker: Usd,
+                code: "USD",
+                exponent: 2,
+                locale: EnUs,
+                minor_units: 1,
+                name: "United States Dollar",
+                symbol: "$",
+                symbol_first: true,
+            },
+            EUR: {
+                marker: Eur,
+                code: "EUR",
+                exponent: 2,
+                locale: EnEu,
+                minor_units: 1,
+                name: "Euro",
+                symbol: "€",
+                symbol_first: true,
+            },
+            GBP: {
+                code: "GBP",
+                exponent: 2,
+                locale: EnUs,
+                minor_units: 1,
+                name: "Pound Sterling",
+                symbol: "£",
+                symbol_first: true,
+            }
+        }
+    );
+
+    #[test]
+    fn typed_money_is_zero_cost() {
+        assert_eq!(
+            std::mem::size_of::<TypedMoney<test::Usd>>(),
+            std::mem::size_of::<Decimal>()
+        );
+    }
+
+    #[test]
+    fn typed_money_arithmetic() {
+        let mut total = TypedMoney::<test::Usd>::from_minor(1_050) + TypedMoney::from_major(20);
+        assert_eq!(total, TypedMoney::from_minor(3_050));
+
+        total -= TypedMoney::from_minor(50);
+        assert_eq!(total, TypedMoney::from_major(30));
+        assert_eq!(-total, TypedMoney::from_major(-30));
+        assert_eq!(total * 2, TypedMoney::from_major(60));
+        assert_eq!(total * Decimal::new(5, 1), TypedMoney::from_major(15));
+        assert!(total > TypedMoney::from_major(29));
+
+        assert_eq!(total.to_string(), "$30.00");
+        assert_eq!(total.currency(), test::USD);
+        assert_eq!(
+            TypedMoney::<test::Eur>::from_minor(100_050).to_string(),
+            "€1.000,50"
+        );
+    }
+
+    #[test]
+    fn typed_money_converts_to_and_from_money() {
+        let money = Money::from_minor(1_000, test::EUR);
+        let typed = TypedMoney::<test::Eur>::try_from_money(&money).unwrap();
+        assert_eq!(typed.amount(), money.amount());
+        assert_eq!(typed.to_money(), money);
+
+        assert_eq!(
+            TypedMoney::<test::Usd>::try_from_money(&money),
+            Err(MoneyError::InvalidCurrency)
+        );
+        assert_eq!(
+            TypedMoney::<test::Usd>::try_from_money(&Money::from_minor(1_000, test::GBP)),
+            Err(MoneyError::InvalidCurrency)
+        );
+    }
+}